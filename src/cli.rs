@@ -0,0 +1,448 @@
+use std::error::Error;
+use std::fmt;
+use std::process;
+use std::thread;
+
+use dos::sim2::record::{MainStat, ArtifactLoadout, Enemy, SUBSTAT_ROLLS};
+use dos::sim2::party::SimulationConfig;
+use dos::sim2::scenario;
+use dos::sim2::types::{Versions, ParticleMode};
+
+#[derive(Debug)]
+pub struct MyError {
+    details: String
+}
+
+impl MyError {
+    pub fn new(msg: &str) -> MyError {
+        MyError { details: msg.to_string() }
+    }
+}
+
+impl fmt::Display for MyError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f,"{}",self.details)
+    }
+}
+
+impl Error for MyError {
+    fn description(&self) -> &str {
+        &self.details
+    }
+}
+
+#[derive(Debug)]
+enum ArgToken {
+    Help,
+    NMembers,
+    CharacterVersion,
+    WeaponVersion,
+    ArtifactVersion,
+    UnitTime,
+    SimulationTime,
+    StartEnergy,
+    Top,
+    TopPerCharacter,
+    Sands,
+    Goblet,
+    Circlet,
+    Rolls,
+    Threads,
+    SearchStrategy,
+    Evaluations,
+    Time,
+    Seed,
+    Svg,
+    Report,
+    Member,
+    Hp,
+    Chamber,
+    Scenario,
+    Particles,
+    ParticleDelay,
+    Standstill,
+    Value(String),
+}
+
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub enum Mode {
+    Simulate,
+    Debugging,
+    Optimize,
+    Search,
+    Rotation,
+    Weights,
+    Ttk,
+    Abyss,
+    Steady,
+    Energy,
+}
+
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub enum Strategy {
+    Beam,
+    Genetic,
+}
+
+#[derive(Debug, Clone)]
+pub struct Args {
+    pub mode: Mode,
+    pub n_members: usize,
+    pub character_version: f32,
+    pub weapon_version: f32,
+    pub artifact_version: f32,
+    pub unit_time: f32,
+    pub simulation_time: f32,
+    pub start_energy: i32,
+    pub top: Option<usize>,
+    pub top_per_character: Option<usize>,
+    pub sands: Vec<Option<MainStat>>,
+    pub goblet: Vec<Option<MainStat>>,
+    pub circlet: Vec<Option<MainStat>>,
+    pub rolls: f32,
+    pub threads: usize,
+    pub strategy: Strategy,
+    pub evaluations: usize,
+    pub time: Option<f32>,
+    pub seed: u64,
+    pub svg: Option<String>,
+    pub report: Option<String>,
+    pub member: usize,
+    pub hp: Vec<f32>,
+    pub chamber: Option<String>,
+    pub scenario: Option<scenario::Scenario>,
+    pub particles: ParticleMode,
+    pub particle_delay: f32,
+    // skip StandStill actions in the trace of `debug`
+    pub skip_standstill: bool,
+    pub party: Vec<String>,
+}
+
+impl Default for Args {
+    fn default() -> Self {
+        Self {
+            mode: Mode::Simulate,
+            n_members: 1,
+            character_version: 2.5,
+            weapon_version: 2.5,
+            artifact_version: 2.5,
+            unit_time: 0.2,
+            simulation_time: 20.0,
+            start_energy: -1,
+            top: None,
+            top_per_character: None,
+            sands: vec![None],
+            goblet: vec![Some(MainStat::DmgBonus)],
+            circlet: vec![None],
+            rolls: SUBSTAT_ROLLS,
+            threads: thread::available_parallelism().map(|n| n.get()).unwrap_or(1),
+            strategy: Strategy::Beam,
+            evaluations: 2000,
+            time: None,
+            seed: 1,
+            svg: None,
+            report: None,
+            member: 0,
+            hp: Vec::new(),
+            chamber: None,
+            scenario: None,
+            particles: ParticleMode::Expected,
+            particle_delay: 0.0,
+            skip_standstill: false,
+            party: Vec::new(),
+        }
+    }
+}
+
+impl Args {
+    pub fn parse<I: Iterator<Item = String>>(env_args: &mut I) -> Result<Args, Box<dyn Error>> {
+        use ArgToken::*;
+        let mut kv: Vec<(ArgToken, ArgToken)> = Vec::new();
+        let mut positional: Vec<String> = Vec::new();
+        // ignore the first
+        env_args.next();
+        let mode = if let Some(string) = env_args.next() {
+            match string.as_str() {
+                "simulate" => Mode::Simulate,
+                "debug" => Mode::Debugging,
+                "optimize" => Mode::Optimize,
+                "search" => Mode::Search,
+                "rotation" => Mode::Rotation,
+                "weights" => Mode::Weights,
+                "ttk" => Mode::Ttk,
+                "abyss" => Mode::Abyss,
+                "steady" => Mode::Steady,
+                "er" => Mode::Energy,
+                _ => {
+                    kv.push((Help, Help));
+                    Mode::Simulate
+                }
+            }
+        } else {
+            kv.push((Help, Help));
+            Mode::Simulate
+        };
+        while let Some(a) = env_args.next() {
+            match a.as_str() {
+                "-h" | "--help" => kv.push((Help, Help)),
+                "--n_members" => kv.push((NMembers, Help)),
+                "--character_version" => kv.push((CharacterVersion, Help)),
+                "--weapon_version" => kv.push((WeaponVersion, Help)),
+                "--artifact_version" => kv.push((ArtifactVersion, Help)),
+                "--unit_time" => kv.push((UnitTime, Help)),
+                "--simulation_time" => kv.push((SimulationTime, Help)),
+                "--start_energy" => kv.push((StartEnergy, Help)),
+                "--top" => kv.push((Top, Help)),
+                "--top-per-character" => kv.push((TopPerCharacter, Help)),
+                "--sands" => kv.push((Sands, Help)),
+                "--goblet" => kv.push((Goblet, Help)),
+                "--circlet" => kv.push((Circlet, Help)),
+                "--rolls" => kv.push((Rolls, Help)),
+                "--threads" => kv.push((Threads, Help)),
+                "--strategy" => kv.push((SearchStrategy, Help)),
+                "--evaluations" => kv.push((Evaluations, Help)),
+                "--time" => kv.push((Time, Help)),
+                "--seed" => kv.push((Seed, Help)),
+                "--svg" => kv.push((Svg, Help)),
+                "--report" => kv.push((Report, Help)),
+                "--member" => kv.push((Member, Help)),
+                "--hp" => kv.push((Hp, Help)),
+                "--chamber" => kv.push((Chamber, Help)),
+                "--scenario" => kv.push((Scenario, Help)),
+                "--particles" => kv.push((Particles, Help)),
+                "--particle_delay" => kv.push((ParticleDelay, Help)),
+                "--standstill" => kv.push((Standstill, Help)),
+                _ => match kv.last_mut() {
+                    Some((_k, v @ Help)) => *v = Value(a),
+                    _ => positional.push(a),
+                },
+            };
+        };
+        let mut args = Args::default();
+        args.mode = mode;
+        // several rotations are needed to find the steady state
+        if mode == Mode::Steady {
+            args.simulation_time = 300.0;
+        }
+        if mode == Mode::Optimize || mode == Mode::Debugging || mode == Mode::Rotation || mode == Mode::Weights || mode == Mode::Ttk || mode == Mode::Steady || mode == Mode::Energy {
+            args.party = positional;
+        } else if !positional.is_empty() {
+            kv.push((Help, Help));
+        }
+        for item in kv.drain(..) {
+            match item {
+                (Help, _) => {
+                    println!("dos is a party damage output simulator.
+
+Usage:
+    dos simulate [--n_members N] [--character_version N] [--weapon_version N] [--artifact_version N] [--threads N] [--top K] [--top-per-character K] [--report FILE] [--sands S] [--goblet S] [--circlet S]
+    dos search [--n_members N] [--strategy S] [--evaluations N] [--time N] [--seed N] [--top K] [--report FILE] [--sands S] [--goblet S] [--circlet S]
+    dos debug [--report FILE] [--standstill S] [--scenario FILE] [--particles S] [--particle_delay N] [--hp N] [--unit_time N] [--simulation_time N] [--start_energy N] [--sands S] [--goblet S] [--circlet S] <character> <weapon> <artifact> [<character> <weapon> <artifact>]...
+    dos rotation [--svg FILE] [--report FILE] [--scenario FILE] [--particles S] [--particle_delay N] [--hp N] [--unit_time N] [--simulation_time N] [--start_energy N] <character> <weapon> <artifact> [<character> <weapon> <artifact>]...
+    dos optimize [--rolls N] [--scenario FILE] [--sands S] [--goblet S] [--circlet S] <character> <weapon> <artifact> [<character> <weapon> <artifact>]...
+    dos ttk --hp N [--scenario FILE] [--simulation_time N] [--start_energy N] [--sands S] [--goblet S] [--circlet S] <character> <weapon> <artifact> [<character> <weapon> <artifact>]...
+    dos steady [--scenario FILE] [--unit_time N] [--simulation_time N] [--sands S] [--goblet S] [--circlet S] <character> <weapon> <artifact> [<character> <weapon> <artifact>]...
+    dos er [--scenario FILE] [--particles S] [--particle_delay N] [--unit_time N] [--simulation_time N] [--start_energy N] [--sands S] [--goblet S] [--circlet S] <character> <weapon> <artifact> [<character> <weapon> <artifact>]...
+    dos abyss --chamber FILE [--simulation_time N] [--start_energy N] [--sands S] [--goblet S] [--circlet S]
+    dos weights [--member N] [--scenario FILE] [--simulation_time N] [--sands S] [--goblet S] [--circlet S] <character> <weapon> <artifact> [<character> <weapon> <artifact>]...
+
+Options:
+    --n_members N         : Number of field members, 1 to 4. `simulate` supports up to 3 because it enumerates all teams, weapons and artifacts [default: 1]
+    --character_version N : characters up to the version will be simulated [default: 2.5]
+    --weapon_version N    : weapons up to the version will be simulated [default: 2.5]
+    --artifact_version N  : artifacts up to the version will be simulated [default: 2.5]
+    --unit_time N         : frequency of character actions [default: 0.2]
+    --simulation_time N   : end the simulation at N seconds [default: 20.0, 300.0 for `steady`]
+    --start_energy N      : amount of energy given to characters at the beginning of the simulation. Negative values mean full energy [default: -1]
+    --threads N           : number of worker threads [default: number of CPU cores]
+    --top K               : output only the best K teams by total damage [default: all]
+    --top-per-character K : output only the best K teams of each on-field character [default: all]
+    --sands S             : comma separated main stats of sands (hp, atk, def, em, er, none) [default: none]
+    --goblet S            : comma separated main stats of goblet (hp, atk, def, em, dmg, elemental, physical, none) [default: dmg]
+    --circlet S           : comma separated main stats of circlet (hp, atk, def, em, cr, cd, healing, none) [default: none]
+    --rolls N             : number of substat rolls distributed by `optimize` [default: 36]
+    --strategy S          : search strategy of `search` (beam, genetic) [default: beam]
+    --evaluations N       : maximum number of teams evaluated by `search` [default: 2000]
+    --time N              : stop `search` after N seconds [default: none]
+    --seed N              : random seed of `search` and sampled particles [default: 1]
+    --svg FILE            : write the rotation to FILE as an SVG image instead of printing it [default: none]
+    --report FILE         : write an HTML report of the party or the best teams to FILE. `simulate` needs --top or --top-per-character [default: none]
+    --member N            : index of the member whose stats are analysed by `weights` [default: 0]
+    --hp N                : comma separated HP of enemies, which are fought one after another [default: infinite]
+    --chamber FILE        : a chamber of the Spiral Abyss with two parties, enemies and ley line disorders [default: none]
+    --scenario FILE       : starting energy, cooldowns and stacks of members and an aura of the enemy. See `Scenario` in src/sim2/scenario.rs for the format [default: none]
+    --particles S         : numbers of particles, which are the expected (possibly fractional) numbers or drawn from their distributions (expected, sampled) [default: expected]
+    --particle_delay N    : seconds until particles reach members. Delayed particles are received as on-field energy by the member on the field when they land [default: 0]
+    --standstill S        : whether `debug` prints ticks of members who stand still (show, skip) [default: show]
+
+`search` looks for the best teams, weapons and artifacts with a heuristic strategy instead of enumerating all of them, and prints the best K (default: 10) teams found. The first main stats given are used.
+
+`debug` runs the party once and prints a trace of actions, energy, queued attacks, changes of stats by `modify` and damages.
+
+`rotation` runs the party once and draws actions, energy and buff windows of each member.
+
+`optimize` distributes substat rolls of the first (on-field) member to maximize the total damage of the party, and prints the best distribution and the damage gained by one more roll of each substat.
+
+`ttk` runs the party against enemies with --hp until all of them are killed or --simulation_time passes, and prints when each enemy was killed.
+
+`steady` runs the party for several rotations, and prints the length and DPS of a rotation after the first ones which start with full energy and all cooldowns ready. A rotation is the time between bursts of the member who uses bursts most often.
+
+`er` prints the minimum ER of each member to use bursts as soon as they are off cooldown, and the energy that each member received from each character, weapon and artifact. Particles are shown before ER, and off-field members receive less energy from them in larger parties.
+
+`abyss` runs each half of the chamber given by --chamber with its own party, and prints when each enemy was killed and the clear time of each half. See `Chamber` in src/sim2/chamber.rs for the format.
+
+`weights` adds each stat (ATK%, CR, CD, EM, ER and DMG bonuses) to a member one at a time, runs the party again and prints the damage gained by one unit of each stat from the largest. ER is simulated with the timeline.
+
+Main stats are enumerated for the on-field member. dmg means the elemental DMG bonus of the character, or the physical DMG bonus for physical attackers.");
+                    process::exit(0);
+                },
+                (NMembers, Value(v)) => args.n_members = v.parse()?,
+                (CharacterVersion, Value(v)) => args.character_version = v.parse()?,
+                (WeaponVersion, Value(v)) => args.weapon_version = v.parse()?,
+                (ArtifactVersion, Value(v)) => args.artifact_version = v.parse()?,
+                (UnitTime, Value(v)) => args.unit_time = v.parse()?,
+                (SimulationTime, Value(v)) => args.simulation_time = v.parse()?,
+                (StartEnergy, Value(v)) => args.start_energy = v.parse()?,
+                (Top, Value(v)) => args.top = Some(v.parse()?),
+                (TopPerCharacter, Value(v)) => args.top_per_character = Some(v.parse()?),
+                (Sands, Value(v)) => args.sands = parse_main_stats(&v)?,
+                (Goblet, Value(v)) => args.goblet = parse_main_stats(&v)?,
+                (Circlet, Value(v)) => args.circlet = parse_main_stats(&v)?,
+                (Rolls, Value(v)) => args.rolls = v.parse()?,
+                (Threads, Value(v)) => args.threads = v.parse()?,
+                (SearchStrategy, Value(v)) => args.strategy = match v.as_str() {
+                    "beam" => Strategy::Beam,
+                    "genetic" => Strategy::Genetic,
+                    _ => return Err(Box::new(MyError::new(&format!("unknown strategy: {}", v)))),
+                },
+                (Evaluations, Value(v)) => args.evaluations = v.parse()?,
+                (Time, Value(v)) => args.time = Some(v.parse()?),
+                (Seed, Value(v)) => args.seed = v.parse()?,
+                (Svg, Value(v)) => args.svg = Some(v),
+                (Report, Value(v)) => args.report = Some(v),
+                (Member, Value(v)) => args.member = v.parse()?,
+                (Hp, Value(v)) => args.hp = v.split(',').map(|x| x.parse()).collect::<Result<Vec<f32>, _>>()?,
+                (Chamber, Value(v)) => args.chamber = Some(v),
+                (Scenario, Value(v)) => args.scenario = Some(scenario::Scenario::load(&v)?),
+                (Particles, Value(v)) => args.particles = match v.as_str() {
+                    "expected" => ParticleMode::Expected,
+                    // the seed is given by --seed
+                    "sampled" => ParticleMode::Sampled(0),
+                    _ => return Err(Box::new(MyError::new(&format!("unknown particle mode: {}", v)))),
+                },
+                (ParticleDelay, Value(v)) => args.particle_delay = v.parse()?,
+                (Standstill, Value(v)) => args.skip_standstill = match v.as_str() {
+                    "show" => false,
+                    "skip" => true,
+                    _ => return Err(Box::new(MyError::new(&format!("unknown standstill option: {}", v)))),
+                },
+                _ => return Err(Box::new(MyError::new("arguments were not recognized."))),
+            }
+        }
+        args.validate()?;
+        Ok(args)
+    }
+
+    fn validate(&self) -> Result<(), Box<dyn Error>> {
+        if self.n_members < 1 || 4 < self.n_members {
+            Err(Box::new(MyError::new("given n_members is not supported now.")))
+        } else if self.mode == Mode::Simulate && self.n_members > 3 {
+            Err(Box::new(MyError::new("simulate supports up to 3 members. Use search for 4 members.")))
+        } else if self.character_version < 0.0 || self.weapon_version < 0.0 || self.artifact_version < 0.0 {
+            Err(Box::new(MyError::new("versions should not be negative.")))
+        } else if self.unit_time < 0.0 || self.simulation_time < 0.0 || self.particle_delay < 0.0 {
+            Err(Box::new(MyError::new("times should not be negative.")))
+        } else if self.rolls < 0.0 {
+            Err(Box::new(MyError::new("rolls should not be negative.")))
+        } else if self.top == Some(0) || self.top_per_character == Some(0) {
+            Err(Box::new(MyError::new("top should be greater than 0.")))
+        } else if self.time.map_or(false, |t| t < 0.0) {
+            Err(Box::new(MyError::new("times should not be negative.")))
+        } else if self.report.is_some() && self.mode == Mode::Simulate && self.top.is_none() && self.top_per_character.is_none() {
+            Err(Box::new(MyError::new("--report needs --top or --top-per-character.")))
+        } else if self.mode == Mode::Weights && self.member >= self.party.len() / 3 {
+            Err(Box::new(MyError::new("given member is not in the party.")))
+        } else if self.hp.iter().any(|x| *x <= 0.0) {
+            Err(Box::new(MyError::new("hp should be greater than 0.")))
+        } else if self.mode == Mode::Ttk && self.hp.is_empty() {
+            Err(Box::new(MyError::new("ttk needs --hp.")))
+        } else if self.mode == Mode::Abyss && self.chamber.is_none() {
+            Err(Box::new(MyError::new("abyss needs --chamber.")))
+        } else if self.threads < 1 {
+            Err(Box::new(MyError::new("threads should be greater than 0.")))
+        } else if self.sands.is_empty() || self.goblet.is_empty() || self.circlet.is_empty() {
+            Err(Box::new(MyError::new("main stats should not be empty.")))
+        } else if self.sands.iter().flatten().any(|x| !x.is_sands()) {
+            Err(Box::new(MyError::new("given main stat is not available for sands.")))
+        } else if self.goblet.iter().flatten().any(|x| !x.is_goblet()) {
+            Err(Box::new(MyError::new("given main stat is not available for goblet.")))
+        } else if self.circlet.iter().flatten().any(|x| !x.is_circlet()) {
+            Err(Box::new(MyError::new("given main stat is not available for circlet.")))
+        } else {
+            Ok(())
+        }
+    }
+
+    pub fn versions(&self) -> Versions {
+        Versions {
+            character: self.character_version,
+            weapon: self.weapon_version,
+            artifact: self.artifact_version,
+        }
+    }
+
+    pub fn config(&self) -> SimulationConfig {
+        SimulationConfig {
+            simulation_time: self.simulation_time,
+            unit_time: self.unit_time,
+            start_energy: if self.start_energy < 0 {
+                None
+            } else {
+                Some(self.start_energy as f32)
+            },
+            unlimited_energy: false,
+            particle: match self.particles {
+                ParticleMode::Sampled(_) => ParticleMode::Sampled(self.seed),
+                ParticleMode::Expected => ParticleMode::Expected,
+            },
+            particle_delay: self.particle_delay,
+        }
+    }
+
+    pub fn enemy(&self) -> Enemy {
+        let enemy = Enemy::hilichurl().waves(&self.hp);
+        match self.scenario {
+            Some(ref scenario) => scenario.enemy(enemy),
+            None => enemy,
+        }
+    }
+
+    // main stat combinations of the on-field member
+    pub fn loadouts(&self) -> Vec<ArtifactLoadout> {
+        ArtifactLoadout::all(&self.sands, &self.goblet, &self.circlet)
+    }
+
+    // main stats of supporters
+    pub fn support_loadout(&self) -> ArtifactLoadout {
+        ArtifactLoadout::new(self.sands[0], self.goblet[0], self.circlet[0])
+    }
+
+    // main stats are written to outputs only if the user asked for them
+    pub fn has_main_stats(&self) -> bool {
+        self.loadouts() != vec![ArtifactLoadout::default()]
+    }
+}
+
+fn parse_main_stats(value: &str) -> Result<Vec<Option<MainStat>>, Box<dyn Error>> {
+    let mut result: Vec<Option<MainStat>> = Vec::new();
+    for key in value.split(',') {
+        if key == "none" {
+            result.push(None);
+        } else if let Some(m) = MainStat::parse(key) {
+            result.push(Some(m));
+        } else {
+            return Err(Box::new(MyError::new("main stats were not recognized.")));
+        }
+    }
+    Ok(result)
+}
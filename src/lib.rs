@@ -0,0 +1,29 @@
+//! dos is a party damage output simulator.
+//!
+//! ```
+//! use dos::{Party, PartyMember, SimulationConfig, Enemy, DamageResultUtil};
+//!
+//! let mut party = Party::new()
+//!     .member(PartyMember::find("Diluc", "Prototype Archaic", "Gladiator's Finale").unwrap())
+//!     .member(PartyMember::find("Bennett", "Prototype Rancour", "Noblesse Oblige").unwrap());
+//! let mut enemy = Enemy::hilichurl();
+//! let result = party.run(&SimulationConfig::default(), &mut enemy).unwrap();
+//! assert!(result.total_damage() > 0.0);
+//! ```
+//!
+//! The items re-exported here are the stable API. Modules under `sim2` are
+//! public for advanced use (e.g. implementing new characters) and may change.
+pub mod sim2;
+
+pub use sim2::party::{Party, PartyMember, PartyError, SimulationConfig, StartState};
+pub use sim2::scenario::Scenario;
+pub use sim2::sections::ParseError;
+pub use sim2::record::{Enemy, Resistance, Wave, ArtifactLoadout, MainStat, Substat, SubstatRolls};
+pub use sim2::attack::{DamageResult, DamageResultUtil};
+pub use sim2::types::{DamageType, Vision, ParticleMode};
+pub use sim2::optimize::{optimize_substats, SubstatReport, stat_weights, Stat, StatWeightReport};
+pub use sim2::chamber::{Chamber, ChamberHalf, HalfResult};
+pub use sim2::steady::{SteadyState, Cycle};
+pub use sim2::energy::{er_requirement, EnergyReport, MemberEnergy, EnergySource};
+pub use sim2::event::EnergyGain;
+pub use sim2::search::{SearchSpace, SearchStrategy, Budget, BeamSearch, Genetic};
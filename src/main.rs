@@ -2,9 +2,10 @@
 // #![feature(unsized_tuple_coercion)]
 #![allow(dead_code, unused)]
 
+mod cli;
+mod runner;
 mod sim1;
-mod sim2;
 
 fn main() {
-    sim2::main2();
+    runner::main();
 }
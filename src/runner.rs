@@ -0,0 +1,883 @@
+use std::error::Error;
+use std::env;
+use std::io;
+use std::fs;
+use std::process;
+use std::cmp::Ordering;
+use std::thread;
+use std::sync::mpsc;
+use std::sync::mpsc::Sender;
+use std::sync::Arc;
+use std::sync::atomic::{self, AtomicUsize};
+use std::time::{Duration};
+use std::collections::{HashMap, BinaryHeap};
+use std::cmp::Reverse;
+
+use crate::sim1::permutools::Permutation3;
+use dos::sim2::{characters, weapons, artifact, training, simulate};
+
+use crate::cli::{Args, Mode, Strategy, MyError};
+use dos::sim2::state::State;
+use dos::sim2::timeline::ActionState;
+use dos::sim2::attack::{Attack, DamageResult, DamageResultUtil};
+use dos::sim2::party::Party;
+use dos::sim2::chamber::Chamber;
+use dos::sim2::steady::SteadyState;
+use dos::sim2::energy::er_requirement;
+use dos::sim2::optimize::{optimize_substats, stat_weights};
+use dos::sim2::trace::TextTrace;
+use dos::sim2::rotation::Rotation;
+use dos::sim2::report::{self, RunRecord};
+use dos::sim2::search::{SearchSpace, SearchStrategy, Budget, BeamSearch, Genetic};
+use dos::sim2::teams::Teams;
+use dos::sim2::simulate::{History, TimelineKey};
+use dos::sim2::types::{CharacterAction, DamageType, Vision, FieldCharacterIndex, Preference, combination_filter};
+use dos::sim2::record::{TimelineMember, FieldMember, CharacterData, CharacterRecord, WeaponRecord, Artifact, ArtifactLoadout, Enemy};
+
+#[derive(Debug)]
+struct Recorder {
+    head: Vec<&'static str>,
+    data: Vec<usize>,
+    // main stats of the on-field member if they are enumerated
+    loadout: Option<ArtifactLoadout>,
+}
+
+impl Ord for Recorder {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.total().cmp(&other.total())
+    }
+}
+
+impl PartialOrd for Recorder {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Eq for Recorder {}
+
+impl PartialEq for Recorder {
+    fn eq(&self, other: &Self) -> bool {
+        self.total() == other.total()
+    }
+}
+
+// keeps the best `k` recorders with a min-heap of size `k`
+struct TopK {
+    k: usize,
+    heap: BinaryHeap<Reverse<Recorder>>,
+}
+
+impl TopK {
+    fn new(k: usize) -> Self {
+        Self {
+            k,
+            heap: BinaryHeap::with_capacity(k + 1),
+        }
+    }
+
+    fn push(&mut self, rc: Recorder) -> () {
+        if self.heap.len() < self.k {
+            self.heap.push(Reverse(rc));
+        } else if let Some(Reverse(min)) = self.heap.peek() {
+            if rc > *min {
+                self.heap.pop();
+                self.heap.push(Reverse(rc));
+            }
+        }
+    }
+
+    // the best first
+    fn into_sorted_vec(self) -> Vec<Recorder> {
+        self.heap.into_sorted_vec().into_iter().map(|Reverse(rc)| rc).collect()
+    }
+}
+
+trait NewRecorder<T> {
+    fn new(end_time: f32, item: T) -> Recorder;
+}
+
+impl NewRecorder<Vec<&'static str>> for Recorder {
+    fn new(end_time: f32,item: Vec<&'static str>) -> Self {
+        Self {
+            head: item,
+            data: vec![0; end_time as usize],
+            loadout: None,
+        }
+    }
+}
+
+impl NewRecorder<&CharacterData<'_>> for Recorder {
+    fn new(end_time: f32,item: &CharacterData) -> Self {
+        Self {
+            head: vec![item.character.name, item.weapon.name, item.artifact.name],
+            data: vec![0; end_time as usize],
+            loadout: None,
+        }
+    }
+}
+
+impl NewRecorder<&(&CharacterRecord, &WeaponRecord, &Artifact)> for Recorder {
+    fn new(end_time: f32,item: &(&CharacterRecord, &WeaponRecord, &Artifact)) -> Self {
+        let (cr, wr, ar) = item;
+        Self {
+            head: vec![cr.name, wr.name, ar.name],
+            data: vec![0; end_time as usize],
+            loadout: None,
+        }
+    }
+}
+
+impl Recorder {
+    fn push_main_stats(&mut self, loadout: &ArtifactLoadout) -> () {
+        self.loadout = Some(*loadout);
+        for name in loadout.head().iter() {
+            self.head.push(name);
+        }
+    }
+
+    // total damage; DPS ranks teams in the same order because every team is
+    // simulated for the same time.
+    fn total(&self) -> usize {
+        self.data.iter().sum()
+    }
+
+    fn record(&mut self, time: f32, value: f32) -> () {
+        // if self.data.len() == time.floor() as usize {
+        //     self.data.push(value.floor() as usize);
+        // }
+        let mut idx = time.floor() as usize;
+        if idx == self.data.len() {
+            idx -= 1;
+        }
+        self.data[idx] += value.floor() as usize;
+    }
+
+    fn make_row(&self) -> Vec<String> {
+        let mut result: Vec<String> = Vec::with_capacity(self.head.len() + self.data.len());
+        // result.append(&mut self.head);
+        for x in &self.head {
+            result.push(x.to_string());
+        }
+        for x in &self.data {
+            result.push(x.to_string());
+        }
+        result
+    }
+}
+
+// the permutation of a party size, which takes the on-field member and the
+// supporters of a team
+type Permu = fn(&Sender<Vec<Recorder>>, usize, &[usize], &Args) -> ();
+
+fn permu3(tx: &Sender<Vec<Recorder>>, i: usize, args: &Args) -> () {
+    let versions = args.versions();
+    let input_characters: Vec<(CharacterRecord, characters::CharacterUnion)> = characters::all().drain(i..i + 1).collect();
+    let mut member1 = Permutation3::new(
+        input_characters,
+        weapons::all(),
+        artifact::all(),
+    );
+
+    let loadouts = args.loadouts();
+    // timelines that cannot be reused are stored at `None` and overwritten
+    let mut cache: HashMap<Option<TimelineKey>, History<1>> = HashMap::new();
+    let mut items: Vec<Recorder> = Vec::new();
+    for ((cr1, mut ca1), (wr1, mut wa1), (mut ar1, mut aa1)) in member1.iter() {
+        if !combination_filter(&cr1, &wr1, &ar1, &versions) {
+            member1.back(((cr1, ca1), (wr1, wa1), (ar1, aa1)));
+            continue;
+        }
+        for loadout in loadouts.iter() {
+            let mut enemy = Enemy::hilichurl();
+            let mut recorder = Recorder::new(args.simulation_time, &(&cr1, &wr1, &ar1));
+            if args.has_main_stats() {
+                recorder.push_main_stats(loadout);
+            }
+            let dmg: Vec<DamageResult>;
+
+            let bare1 = ar1.clone();
+
+            ar1.equip(loadout, &cr1.vision, &cr1.name);
+
+            let mut data = [CharacterData::new(0, &cr1, &wr1, &ar1); 1];
+            let key = {
+                let mut members = [TimelineMember {
+                    character: ca1.timeline(),
+                    weapon: wa1.timeline(),
+                    artifact: aa1.timeline(),
+                }; 1];
+                let key = TimelineKey::new(&members, &data);
+                if key.is_none() || !cache.contains_key(&key) {
+                    let mut history = History::<1>::new(args.simulation_time, args.unit_time);
+                    let mut states = [ActionState::new(); 1];
+                    states[0].energy = if args.start_energy < 0 {
+                        cr1.energy_cost
+                    } else {
+                        args.start_energy as f32
+                    };
+                    simulate::decide_action(&mut history, &mut members, &mut states, &mut data);
+                    members[0].character.reset_timeline();
+                    members[0].weapon.reset_timeline();
+                    members[0].artifact.reset_timeline();
+                    cache.insert(key.clone(), history);
+                }
+                key
+            };
+            let history = cache.get_mut(&key).unwrap();
+            {
+                let mut members = [FieldMember {
+                    character: ca1.field(),
+                    weapon: wa1.field(),
+                    artifact: aa1.field(),
+                }; 1];
+                dmg = simulate::calculate_damage(history, &mut members, &mut data, &mut enemy);
+                members[0].character.reset_modify();
+                members[0].weapon.reset_modify();
+                members[0].artifact.reset_modify();
+            }
+
+            // destruct objects
+            ar1 = bare1;
+            for r in dmg.iter() {
+                recorder.record(r.time, r.total_damage());
+            }
+            items.push(recorder);
+        }
+        member1.back(((cr1, ca1), (wr1, wa1), (ar1, aa1)));
+    }
+    tx.send(items).unwrap();
+}
+
+fn permu6(tx: &Sender<Vec<Recorder>>, i: usize, j: usize, args: &Args) -> () {
+    let versions = args.versions();
+    let input_characters: Vec<(CharacterRecord, characters::CharacterUnion)> = characters::all().drain(i..i + 1).collect();
+    let mut member1 = Permutation3::new(
+        input_characters,
+        weapons::all(),
+        artifact::all(),
+    );
+    let mut member2 = Permutation3::new(
+        characters::all().drain(j..j + 1).collect(),
+        weapons::all(),
+        artifact::all(),
+    );
+    // let mut member1 = Permutation3::new(
+    //     input_characters,
+    //     training::weapons(),
+    //     training::artifacts(),
+    // );
+    // let mut member2 = Permutation3::new(
+    //     characters::all(),
+    //     training::weapons(),
+    //     training::artifacts(),
+    // );
+
+    let loadouts = args.loadouts();
+    let support_loadout = args.support_loadout();
+    // timelines that cannot be reused are stored at `None` and overwritten
+    let mut cache: HashMap<Option<TimelineKey>, History<2>> = HashMap::new();
+    for ((cr1, mut ca1), (wr1, mut wa1), (mut ar1, mut aa1)) in member1.iter() {
+        if !combination_filter(&cr1, &wr1, &ar1, &versions) {
+            member1.back(((cr1, ca1), (wr1, wa1), (ar1, aa1)));
+            continue;
+        }
+        for loadout in loadouts.iter() {
+            let bare1 = ar1.clone();
+            ar1.equip(loadout, &cr1.vision, &cr1.name);
+            let mut items: Vec<Recorder> = Vec::new();
+            for ((cr2, mut ca2), (wr2, mut wa2), (mut ar2, mut aa2)) in member2.iter() {
+                if cr1.name == cr2.name || !combination_filter(&cr2, &wr2, &ar2, &versions) {
+                    member2.back(((cr2, ca2), (wr2, wa2), (ar2, aa2)));
+                    continue;
+                }
+
+                let mut enemy = Enemy::hilichurl();
+                let dmg: Vec<DamageResult>;
+
+                // supporter role
+                // ar2.atk_spd = -90.;
+                let bare2 = ar2.clone();
+                ar2.equip(&support_loadout, &cr2.vision, &cr2.name);
+
+                let mut data = [CharacterData::new(0, &cr1, &wr1, &ar1),CharacterData::new(1, &cr2, &wr2, &ar2),];
+                let mut head: Vec<&'static str> = Vec::new();
+                for i in data.iter() {
+                    head.push(i.character.name);
+                    head.push(i.weapon.name);
+                    head.push(i.artifact.name);
+                }
+                let mut recorder = Recorder::new(args.simulation_time, head);
+                if args.has_main_stats() {
+                    recorder.push_main_stats(loadout);
+                }
+                let key = {
+                    let mut members = [TimelineMember {
+                        character: ca1.timeline(),
+                        weapon: wa1.timeline(),
+                        artifact: aa1.timeline(),
+                    }, TimelineMember {
+                        character: ca2.timeline(),
+                        weapon: wa2.timeline(),
+                        artifact: aa2.timeline(),
+                    }, ];
+                    let key = TimelineKey::new(&members, &data);
+                    if key.is_none() || !cache.contains_key(&key) {
+                        let mut history = History::<2>::new(args.simulation_time, args.unit_time);
+                        let mut states = [ActionState::new(); 2];
+                        states[0].energy = if args.start_energy < 0 {
+                            cr1.energy_cost
+                        } else {
+                            args.start_energy as f32
+                        };
+                        states[1].energy = if args.start_energy < 0 {
+                            cr2.energy_cost
+                        } else {
+                            args.start_energy as f32
+                        };
+                        simulate::decide_action(&mut history, &mut members, &mut states, &mut data);
+                        for m in members.iter_mut() {
+                            m.character.reset_timeline();
+                            m.weapon.reset_timeline();
+                            m.artifact.reset_timeline();
+                        }
+                        cache.insert(key.clone(), history);
+                    }
+                    key
+                };
+                let history = cache.get_mut(&key).unwrap();
+                {
+                    let mut members = [FieldMember {
+                        character: ca1.field(),
+                        weapon: wa1.field(),
+                        artifact: aa1.field(),
+                    }, FieldMember {
+                        character: ca2.field(),
+                        weapon: wa2.field(),
+                        artifact: aa2.field(),
+                    }, ];
+                    dmg = simulate::calculate_damage(history, &mut members, &mut data, &mut enemy);
+                    for m in members.iter_mut() {
+                        m.character.reset_modify();
+                        m.weapon.reset_modify();
+                        m.artifact.reset_modify();
+                    }
+                }
+
+                // destruct objects
+                ar2 = bare2;
+                member2.back(((cr2, ca2), (wr2, wa2), (ar2, aa2)));
+                for r in dmg.iter() {
+                    recorder.record(r.time, r.total_damage());
+                }
+                items.push(recorder);
+            }
+            ar1 = bare1;
+            tx.send(items).unwrap();
+        }
+        member1.back(((cr1, ca1), (wr1, wa1), (ar1, aa1)));
+    }
+}
+
+fn permu9(tx: &Sender<Vec<Recorder>>, i: usize, j: usize, k: usize, args: &Args) -> () {
+    let versions = args.versions();
+    let input_characters: Vec<(CharacterRecord, characters::CharacterUnion)> = characters::all().drain(i..i + 1).collect();
+    let mut member1 = Permutation3::new(
+        input_characters,
+        training::weapons(),
+        training::artifacts(),
+    );
+    let mut member2 = Permutation3::new(
+        characters::all().drain(j..j + 1).collect(),
+        training::weapons(),
+        training::artifacts(),
+    );
+    let mut member3 = Permutation3::new(
+        characters::all().drain(k..k + 1).collect(),
+        training::weapons(),
+        training::artifacts(),
+    );
+
+    let loadouts = args.loadouts();
+    let support_loadout = args.support_loadout();
+    // timelines that cannot be reused are stored at `None` and overwritten
+    let mut cache: HashMap<Option<TimelineKey>, History<3>> = HashMap::new();
+    for ((cr1, mut ca1), (wr1, mut wa1), (mut ar1, mut aa1)) in member1.iter() {
+        if !combination_filter(&cr1, &wr1, &ar1, &versions) {
+            member1.back(((cr1, ca1), (wr1, wa1), (ar1, aa1)));
+            continue;
+        }
+        for loadout in loadouts.iter() {
+            let bare1 = ar1.clone();
+            ar1.equip(loadout, &cr1.vision, &cr1.name);
+            for ((cr2, mut ca2), (wr2, mut wa2), (mut ar2, mut aa2)) in member2.iter() {
+                if cr1.name == cr2.name || !combination_filter(&cr2, &wr2, &ar2, &versions) {
+                    member2.back(((cr2, ca2), (wr2, wa2), (ar2, aa2)));
+                    continue;
+                }
+
+                // supporter role
+                // ar2.atk_spd = -90.;
+                let bare2 = ar2.clone();
+                ar2.equip(&support_loadout, &cr2.vision, &cr2.name);
+
+                let mut items: Vec<Recorder> = Vec::new();
+                for ((cr3, mut ca3), (wr3, mut wa3), (mut ar3, mut aa3)) in member3.iter() {
+                    if cr1.name == cr3.name || cr2.name == cr3.name || !combination_filter(&cr3, &wr3, &ar3, &versions) {
+                        member3.back(((cr3, ca3), (wr3, wa3), (ar3, aa3)));
+                        continue;
+                    }
+
+                    // supporter role
+                    // ar3.atk_spd = -90.;
+                    let bare3 = ar3.clone();
+                    ar3.equip(&support_loadout, &cr3.vision, &cr3.name);
+
+                    let mut enemy = Enemy::hilichurl();
+                    let dmg: Vec<DamageResult>;
+
+                    let mut data = [CharacterData::new(0, &cr1, &wr1, &ar1),CharacterData::new(1, &cr2, &wr2, &ar2),CharacterData::new(2, &cr3, &wr3, &ar3),];
+                    let mut head: Vec<&'static str> = Vec::new();
+                    for i in data.iter() {
+                        head.push(i.character.name);
+                        head.push(i.weapon.name);
+                        head.push(i.artifact.name);
+                    }
+                    let mut recorder = Recorder::new(args.simulation_time, head);
+                    if args.has_main_stats() {
+                        recorder.push_main_stats(loadout);
+                    }
+                    let key = {
+                        let mut members = [TimelineMember {
+                            character: ca1.timeline(),
+                            weapon: wa1.timeline(),
+                            artifact: aa1.timeline(),
+                        }, TimelineMember {
+                            character: ca2.timeline(),
+                            weapon: wa2.timeline(),
+                            artifact: aa2.timeline(),
+                        }, TimelineMember {
+                            character: ca3.timeline(),
+                            weapon: wa3.timeline(),
+                            artifact: aa3.timeline(),
+                        }, ];
+                        let key = TimelineKey::new(&members, &data);
+                        if key.is_none() || !cache.contains_key(&key) {
+                            let mut history = History::<3>::new(args.simulation_time, args.unit_time);
+                            let mut states = [ActionState::new(); 3];
+                            if args.start_energy < 0 {
+                                states[0].energy = cr1.energy_cost;
+                                states[1].energy = cr2.energy_cost;
+                                states[2].energy = cr3.energy_cost;
+                            } else {
+                                let energy_cost = args.start_energy as f32;
+                                states[0].energy = energy_cost;
+                                states[1].energy = energy_cost;
+                                states[2].energy = energy_cost;
+                            };
+                            simulate::decide_action(&mut history, &mut members, &mut states, &mut data);
+                            for m in members.iter_mut() {
+                                m.character.reset_timeline();
+                                m.weapon.reset_timeline();
+                                m.artifact.reset_timeline();
+                            }
+                            cache.insert(key.clone(), history);
+                        }
+                        key
+                    };
+                    let history = cache.get_mut(&key).unwrap();
+                    {
+                        let mut members = [FieldMember {
+                            character: ca1.field(),
+                            weapon: wa1.field(),
+                            artifact: aa1.field(),
+                        }, FieldMember {
+                            character: ca2.field(),
+                            weapon: wa2.field(),
+                            artifact: aa2.field(),
+                        }, FieldMember {
+                            character: ca3.field(),
+                            weapon: wa3.field(),
+                            artifact: aa3.field(),
+                        }, ];
+                        dmg = simulate::calculate_damage(history, &mut members, &mut data, &mut enemy);
+                        for m in members.iter_mut() {
+                            m.character.reset_modify();
+                            m.weapon.reset_modify();
+                            m.artifact.reset_modify();
+                        }
+                    }
+
+                    // destruct objects
+                    ar3 = bare3;
+                    member3.back(((cr3, ca3), (wr3, wa3), (ar3, aa3)));
+                    for r in dmg.iter() {
+                        recorder.record(r.time, r.total_damage());
+                    }
+                    items.push(recorder);
+                }
+                ar2 = bare2;
+                member2.back(((cr2, ca2), (wr2, wa2), (ar2, aa2)));
+                tx.send(items).unwrap();
+            }
+            ar1 = bare1;
+        }
+        member1.back(((cr1, ca1), (wr1, wa1), (ar1, aa1)));
+    }
+}
+
+fn optimize(args: &Args) -> Result<(), Box<dyn Error + 'static>> {
+    let mut party = Party::find(&args.party)?;
+    if let Some(ref scenario) = args.scenario {
+        scenario.apply(&mut party)?;
+    }
+    let support_loadout = args.support_loadout();
+    for m in party.members.iter_mut().skip(1) {
+        m.loadout = support_loadout;
+    }
+    for loadout in args.loadouts().iter() {
+        party.members[0].loadout = *loadout;
+        let report = optimize_substats(&mut party, args.rolls, &args.config())?;
+        let [sands, goblet, circlet] = loadout.head();
+        let m = &party.members[0];
+        println!("{} / {} / {} ({}, {}, {})", m.character.0.name, m.weapon.0.name, m.artifact.0.name, sands, goblet, circlet);
+        println!("  total damage: {:.0}", report.damage);
+        println!("  {:<6} {:>6} {:>8} {:>14}", "stat", "rolls", "value", "gain per roll");
+        for (s, gain) in report.marginal_gain.iter() {
+            let n = report.rolls.get(s);
+            let ratio = if report.damage > 0. { 100. * gain / report.damage } else { 0. };
+            println!("  {:<6} {:>6} {:>8.1} {:>8.0} ({:+.2}%)", s.name(), n, n * s.roll_value(), gain, ratio);
+        }
+    }
+    Ok(())
+}
+
+fn ttk(args: &Args) -> Result<(), Box<dyn Error + 'static>> {
+    let mut party = find_party(args)?;
+    let mut enemy = args.enemy();
+    let dmg = party.run(&args.config(), &mut enemy)?;
+    println!("total damage: {:.0}", dmg.total_damage());
+    for (i, t) in enemy.kills.iter().enumerate() {
+        println!("enemy {} killed at {:.2}s", i + 1, t);
+    }
+    match enemy.clear_time() {
+        Some(t) => println!("cleared in {:.2}s", t),
+        None => println!("not cleared in {}s: {} enemies and {:.0} HP left", args.simulation_time, enemy.waves.len() + 1, enemy.hp_left()),
+    };
+    Ok(())
+}
+
+fn weights(args: &Args) -> Result<(), Box<dyn Error + 'static>> {
+    let mut party = find_party(args)?;
+    let report = stat_weights(&mut party, args.member, &args.config())?;
+    let m = &party.members[args.member];
+    println!("{} / {} / {}", m.character.0.name, m.weapon.0.name, m.artifact.0.name);
+    println!("  total damage: {:.0}", report.damage);
+    println!("  {:<12} {:>14} {:>10}", "stat", "gain per unit", "relative");
+    let best = report.weights.first().map_or(0., |(_, w)| *w);
+    for (s, w) in report.weights.iter() {
+        let relative = if best > 0. { w / best } else { 0. };
+        println!("  {:<12} {:>14.1} {:>10.2}", s.name(), w, relative);
+    }
+    Ok(())
+}
+
+fn energy(args: &Args) -> Result<(), Box<dyn Error + 'static>> {
+    let mut party = find_party(args)?;
+    let report = er_requirement(&mut party, &args.config())?;
+    for m in report.members.iter() {
+        let times: Vec<String> = m.bursts.iter().map(|t| format!("{:.1}s", t)).collect();
+        println!("{} (energy cost {}, bursts at {})", m.name, m.energy_cost, if times.is_empty() { "none".to_string() } else { times.join(", ") });
+        if m.required_er.is_finite() {
+            println!("  ER: {:.1}%, required: {:.1}%", m.er, m.required_er);
+        } else {
+            println!("  ER: {:.1}%, required: not reachable by particles", m.er);
+        }
+        println!("  {:<28} {:>10} {:>8}", "source", "particles", "flat");
+        for s in m.sources.iter() {
+            println!("  {:<28} {:>10.1} {:>8.1}", s.source, s.particle, s.flat);
+        }
+    }
+    Ok(())
+}
+
+fn steady(args: &Args) -> Result<(), Box<dyn Error + 'static>> {
+    let mut party = find_party(args)?;
+    let mut tracer = SteadyState::new(args.unit_time);
+    let dmg = party.run_with(&args.config(), &mut args.enemy(), &mut tracer)?;
+    println!("average DPS over {}s: {:.0}", args.simulation_time, dmg.total_damage() / args.simulation_time);
+    let cycle = match tracer.cycle() {
+        Some(cycle) => cycle,
+        None => {
+            println!("no repeating rotation was found in {}s. try a longer --simulation_time.", args.simulation_time);
+            return Ok(());
+        },
+    };
+    println!("rotation length: {:.2}s", cycle.length());
+    if cycle.exact {
+        println!("steady state: {:.2}s to {:.2}s, where energy and cooldowns of all members repeat", cycle.start, cycle.end);
+    } else {
+        println!("steady state: {:.2}s to {:.2}s, averaged over {} rotations", cycle.start, cycle.end, cycle.rotations);
+    }
+    println!("DPS: {:.0}", cycle.dps());
+    for (name, damage) in cycle.members.iter() {
+        let ratio = if cycle.damage > 0. { 100. * damage / cycle.damage } else { 0. };
+        println!("  {:<20} {:>10.0} ({:.1}%)", name, damage / (cycle.end - cycle.start), ratio);
+    }
+    Ok(())
+}
+
+fn abyss(args: &Args) -> Result<(), Box<dyn Error + 'static>> {
+    let chamber = Chamber::load(args.chamber.as_ref().unwrap())?;
+    let mut total = Some(0.);
+    for (h, name) in chamber.halves.iter().zip(["first", "second"].iter()) {
+        let mut party = find_party_of(args, &h.party)?;
+        let result = h.run(&mut party, &args.config())?;
+        let names: Vec<&str> = party.members.iter().map(|m| m.character.0.name).collect();
+        println!("{} half: {}", name, names.join(", "));
+        println!("  total damage: {:.0}", result.damage);
+        for (i, t) in result.kills.iter().enumerate() {
+            println!("  enemy {} killed at {:.2}s", i + 1, t);
+        }
+        match result.clear_time {
+            Some(t) => println!("  cleared in {:.2}s", t),
+            None => println!("  not cleared in {}s: {} enemies and {:.0} HP left", args.simulation_time, result.enemies_left, result.hp_left),
+        };
+        total = total.and_then(|x| result.clear_time.map(|t| x + t));
+    }
+    match total {
+        Some(t) => println!("chamber cleared in {:.2}s", t),
+        None => println!("chamber not cleared"),
+    };
+    Ok(())
+}
+
+// the party given by positional arguments with the first main stats
+fn find_party(args: &Args) -> Result<Party, Box<dyn Error + 'static>> {
+    let mut party = find_party_of(args, &args.party)?;
+    if let Some(ref scenario) = args.scenario {
+        scenario.apply(&mut party)?;
+    }
+    Ok(party)
+}
+
+fn find_party_of(args: &Args, names: &[String]) -> Result<Party, Box<dyn Error + 'static>> {
+    let mut party = Party::find(names)?;
+    party.members[0].loadout = args.loadouts()[0];
+    let support_loadout = args.support_loadout();
+    for m in party.members.iter_mut().skip(1) {
+        m.loadout = support_loadout;
+    }
+    Ok(party)
+}
+
+// run each team again and write an HTML report if `--report` is given. Each
+// team is a list of character, weapon and artifact names.
+fn write_report(args: &Args, title: &str, teams: &[(Vec<String>, Option<ArtifactLoadout>)]) -> Result<(), Box<dyn Error + 'static>> {
+    let path = match args.report {
+        Some(ref path) => path,
+        None => return Ok(()),
+    };
+    let config = args.config();
+    let mut runs: Vec<RunRecord> = Vec::with_capacity(teams.len());
+    for (names, loadout) in teams.iter() {
+        let mut args = args.clone();
+        args.party = names.clone();
+        let mut party = find_party(&args)?;
+        if let Some(loadout) = loadout {
+            party.members[0].loadout = *loadout;
+        }
+        let title = names.chunks(3).map(|x| x.join(" / ")).collect::<Vec<String>>().join(", ");
+        runs.push(RunRecord::run(&title, &mut party, &config, &mut args.enemy())?);
+    }
+    fs::write(path, report::html(title, &runs))?;
+    Ok(())
+}
+
+fn debug(args: &Args) -> Result<(), Box<dyn Error + 'static>> {
+    let mut party = find_party(args)?;
+    let mut tracer = TextTrace::new().skip_standstill(args.skip_standstill);
+    let dmg = party.run_with(&args.config(), &mut args.enemy(), &mut tracer)?;
+    print!("{}", tracer);
+    println!("total damage: {:.0}", dmg.total_damage());
+    write_report(args, "dos debug", &[(args.party.clone(), None)])
+}
+
+fn rotation(args: &Args) -> Result<(), Box<dyn Error + 'static>> {
+    let mut party = find_party(args)?;
+    let config = args.config();
+    let mut rotation = Rotation::new(config.unit_time);
+    party.run_with(&config, &mut args.enemy(), &mut rotation)?;
+    match args.svg {
+        Some(ref path) => fs::write(path, rotation.to_svg())?,
+        None => print!("{}", rotation.to_ascii()),
+    };
+    write_report(args, "dos rotation", &[(args.party.clone(), None)])
+}
+
+fn search(args: &Args) -> Result<(), Box<dyn Error + 'static>> {
+    let mut space = SearchSpace::new(args.n_members, &args.versions(), args.config())?
+        .loadout(args.loadouts()[0])
+        .support_loadout(args.support_loadout());
+    let budget = Budget {
+        evaluations: args.evaluations,
+        time: args.time.map(Duration::from_secs_f32),
+    };
+    let mut strategy: Box<dyn SearchStrategy> = match args.strategy {
+        Strategy::Beam => Box::new(BeamSearch::new(10, 5, args.seed)),
+        Strategy::Genetic => Box::new(Genetic::new(30, 0.3, args.seed)),
+    };
+    strategy.search(&mut space, &budget);
+    let mut wtr = csv::Writer::from_writer(io::stdout());
+    let mut teams: Vec<(Vec<String>, Option<ArtifactLoadout>)> = Vec::new();
+    for (c, score) in space.best(args.top.unwrap_or(10)).iter() {
+        let names: Vec<String> = space.names(c).iter().map(|x| x.to_string()).collect();
+        let mut row = names.clone();
+        row.push(format!("{:.0}", score));
+        wtr.write_record(&row)?;
+        teams.push((names, None));
+    }
+    wtr.flush()?;
+    write_report(args, "dos search", &teams)
+}
+
+// A unit of work is a team of character indices. Threads take the next team
+// from a shared counter, so that a thread given expensive characters does not
+// keep the others idle.
+fn work_items(n_members: usize, n_characters: usize) -> Vec<(usize, Vec<usize>)> {
+    Teams::new(n_characters, n_members).collect()
+}
+
+fn start_and_wait() -> Result<(), Box<dyn Error + 'static>> {
+    let args = Args::parse(&mut env::args())?;
+    if args.mode == Mode::Debugging {
+        return debug(&args);
+    }
+    if args.mode == Mode::Optimize {
+        return optimize(&args);
+    }
+    if args.mode == Mode::Rotation {
+        return rotation(&args);
+    }
+    if args.mode == Mode::Search {
+        return search(&args);
+    }
+    if args.mode == Mode::Weights {
+        return weights(&args);
+    }
+    if args.mode == Mode::Ttk {
+        return ttk(&args);
+    }
+    if args.mode == Mode::Abyss {
+        return abyss(&args);
+    }
+    if args.mode == Mode::Steady {
+        return steady(&args);
+    }
+    if args.mode == Mode::Energy {
+        return energy(&args);
+    }
+    let permu: Permu = match args.n_members {
+        1 => |tx, i, _, args| permu3(tx, i, args),
+        2 => |tx, i, supporters, args| permu6(tx, i, supporters[0], args),
+        3 => |tx, i, supporters, args| permu9(tx, i, supporters[0], supporters[1], args),
+        _ => return Err(Box::new(MyError::new("simulate supports up to 3 members. Use search for 4 members."))),
+    };
+    let items = Arc::new(work_items(args.n_members, characters::all().len()));
+    let next = Arc::new(AtomicUsize::new(0));
+    let (tx, rx) = mpsc::channel();
+    for _ in 0..args.threads {
+        let txn = tx.clone();
+        let args = args.clone();
+        let items = Arc::clone(&items);
+        let next = Arc::clone(&next);
+        thread::spawn(move || loop {
+            let k = next.fetch_add(1, atomic::Ordering::Relaxed);
+            if k >= items.len() {
+                break;
+            }
+            let (i, ref supporters) = items[k];
+            permu(&txn, i, supporters, &args);
+        });
+    }
+    drop(tx);
+    let mut wtr = csv::Writer::from_writer(io::stdout());
+    if args.top.is_none() && args.top_per_character.is_none() {
+        for received in rx {
+            for rc in received.iter() {
+                wtr.write_record(&rc.make_row())?;
+            }
+            wtr.flush()?;
+        }
+        return Ok(());
+    }
+    // results are kept until all threads finish, so that the output is
+    // exactly the best teams.
+    let mut best: Vec<Recorder> = if let Some(k) = args.top_per_character {
+        let mut heaps: HashMap<&'static str, TopK> = HashMap::new();
+        for received in rx {
+            for rc in received {
+                heaps.entry(rc.head[0]).or_insert_with(|| TopK::new(k)).push(rc);
+            }
+        }
+        heaps.into_iter().flat_map(|(_, heap)| heap.into_sorted_vec()).collect()
+    } else {
+        let mut heap = TopK::new(args.top.unwrap());
+        for received in rx {
+            for rc in received {
+                heap.push(rc);
+            }
+        }
+        heap.into_sorted_vec()
+    };
+    if let (Some(_), Some(k)) = (args.top_per_character, args.top) {
+        let mut heap = TopK::new(k);
+        for rc in best.drain(..) {
+            heap.push(rc);
+        }
+        best = heap.into_sorted_vec();
+    }
+    best.sort_by(|a, b| b.cmp(a));
+    for rc in best.iter() {
+        wtr.write_record(&rc.make_row())?;
+    }
+    wtr.flush()?;
+    let teams: Vec<(Vec<String>, Option<ArtifactLoadout>)> = best.iter()
+        .map(|rc| (rc.head[..3 * args.n_members].iter().map(|x| x.to_string()).collect(), rc.loadout))
+        .collect();
+    write_report(&args, "dos simulate", &teams)?;
+    Ok(())
+}
+
+pub fn main() {
+    // thread::sleep(Duration::from_secs(10));
+    if let Err(err) = start_and_wait() {
+        println!("{:?}", err);
+        process::exit(1);
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn recorder(name: &'static str, data: Vec<usize>) -> Recorder {
+        Recorder {
+            head: vec![name],
+            data,
+            loadout: None,
+        }
+    }
+
+    #[test]
+    fn top_k() {
+        let mut heap = TopK::new(2);
+        heap.push(recorder("a", vec![1, 9]));
+        heap.push(recorder("b", vec![8, 8]));
+        heap.push(recorder("c", vec![5, 6]));
+        heap.push(recorder("d", vec![3, 3]));
+        let best: Vec<&'static str> = heap.into_sorted_vec().iter().map(|rc| rc.head[0]).collect();
+        // ranked by the total rather than the last second
+        assert_eq!(best, vec!["b", "c"]);
+    }
+}
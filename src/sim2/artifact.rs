@@ -1,9 +1,11 @@
+use std::sync::OnceLock;
+
 use crate::sim2::state::State;
 use crate::sim2::timeline::{ActionState, Timeline};
-use crate::sim2::attack::{Attack, WeaponAttack};
-use crate::sim2::types::{DamageType, CharacterAction, WeaponType, FieldEnergy, Preference, Vision, GearScore, SCORE, NOBLESSE_OBLIGE, TENACITY_OF_THE_MILLELITH};
+use crate::sim2::attack::{Attack, Scaling, WeaponAttack};
+use crate::sim2::types::{DamageType, CharacterAction, WeaponType, FieldEnergy, Preference, Vision, NOBLESSE_OBLIGE, TENACITY_OF_THE_MILLELITH};
 use crate::sim2::element::{ElementalGauge, ElementalReactionType, ElementalReaction, PHYSICAL_GAUGE};
-use crate::sim2::record::{CharacterData, Artifact, Enemy};
+use crate::sim2::record::{CharacterData, Artifact, SubstatRolls, Enemy, Debuff, DebuffStat};
 
 use DamageType::*;
 
@@ -26,10 +28,6 @@ pub enum ArtifactUnion {
     Thundersoother(Thundersoother),
     Lavawalker(Lavawalker),
     LavawalkerHp(LavawalkerHp),
-    Gfelm(Gfelm),
-    Gfelm1(Gfelm1),
-    Gfelm2(Gfelm2),
-    GfelmHpCr(GfelmHpCr),
     BlizzardStrayer(BlizzardStrayer),
     HeartOfDepth(HeartOfDepth),
     GlacierAndSnowfield(GlacierAndSnowfield),
@@ -38,13 +36,13 @@ pub enum ArtifactUnion {
     TenacityOfTheMillelithHP(TenacityOfTheMillelithHP),
     TenacityOfTheMillelithDEF(TenacityOfTheMillelithDEF),
     ShimenawasReminiscence(ShimenawasReminiscence),
-    GfShimenawa(GfShimenawa),
     EmblemOfSeveredFate(EmblemOfSeveredFate),
     EmblemOfSeveredFateER(EmblemOfSeveredFateER),
     HuskOfOpulentDreams(HuskOfOpulentDreams),
     HuskOfOpulentDreamsAlbedo(HuskOfOpulentDreamsAlbedo),
     OceanHuedClam(OceanHuedClam),
     OceanHuedClamKokomi(OceanHuedClamKokomi),
+    TwoPiece(TwoPiece),
 }
 
 impl ArtifactUnion {
@@ -68,10 +66,6 @@ impl ArtifactUnion {
             Thundersoother(x) => x,
             Lavawalker(x) => x,
             LavawalkerHp(x) => x,
-            Gfelm(x) => x,
-            Gfelm1(x) => x,
-            Gfelm2(x) => x,
-            GfelmHpCr(x) => x,
             BlizzardStrayer(x) => x,
             HeartOfDepth(x) => x,
             GlacierAndSnowfield(x) => x,
@@ -80,13 +74,13 @@ impl ArtifactUnion {
             TenacityOfTheMillelithHP(x) => x,
             TenacityOfTheMillelithDEF(x) => x,
             ShimenawasReminiscence(x) => x,
-            GfShimenawa(x) => x,
             EmblemOfSeveredFate(x) => x,
             EmblemOfSeveredFateER(x) => x,
             HuskOfOpulentDreams(x) => x,
             HuskOfOpulentDreamsAlbedo(x) => x,
             OceanHuedClam(x) => x,
             OceanHuedClamKokomi(x) => x,
+            TwoPiece(x) => x,
         }
     }
 
@@ -110,10 +104,6 @@ impl ArtifactUnion {
             Thundersoother(x) => x,
             Lavawalker(x) => x,
             LavawalkerHp(x) => x,
-            Gfelm(x) => x,
-            Gfelm1(x) => x,
-            Gfelm2(x) => x,
-            GfelmHpCr(x) => x,
             BlizzardStrayer(x) => x,
             HeartOfDepth(x) => x,
             GlacierAndSnowfield(x) => x,
@@ -122,20 +112,162 @@ impl ArtifactUnion {
             TenacityOfTheMillelithHP(x) => x,
             TenacityOfTheMillelithDEF(x) => x,
             ShimenawasReminiscence(x) => x,
-            GfShimenawa(x) => x,
             EmblemOfSeveredFate(x) => x,
             EmblemOfSeveredFateER(x) => x,
             HuskOfOpulentDreams(x) => x,
             HuskOfOpulentDreamsAlbedo(x) => x,
             OceanHuedClam(x) => x,
             OceanHuedClamKokomi(x) => x,
+            TwoPiece(x) => x,
         }
     }
 }
 
+// 4 piece sets, 2 + 2 piece sets and rainbow sets
 pub fn all() -> Vec<(Artifact, ArtifactUnion)> {
+    let mut result = four_piece();
+    for ar in two_plus_two().iter().chain(rainbow().iter()) {
+        result.push((ar.clone(), ArtifactUnion::TwoPiece(TwoPiece)));
+    }
+    result
+}
+
+// 2 piece bonuses that affect damage. Sets whose 2 piece bonus is RES or
+// shield strength are omitted.
+pub fn two_piece() -> Vec<Artifact> {
+    vec![
+    Bcpf::two_piece(),
+    ThunderingFury::two_piece(),
+    ViridescentVenerer::two_piece(),
+    ArchaicPetra::two_piece(),
+    CrimsonWitchOfFlames::two_piece(),
+    NoblesseOblige::two_piece(),
+    GladiatorsFinale::two_piece(),
+    WanderersTroupe::two_piece(),
+    BlizzardStrayer::two_piece(),
+    HeartOfDepth::two_piece(),
+    GlacierAndSnowfield::two_piece(),
+    PaleFlame::two_piece(),
+    TenacityOfTheMillelith::two_piece(),
+    ShimenawasReminiscence::two_piece(),
+    EmblemOfSeveredFate::two_piece(),
+    HuskOfOpulentDreams::two_piece(),
+    ]
+}
+
+// Characters whose damage scales with HP or DEF, and their elements. 2 + 2
+// and rainbow sets have variants with HP or DEF substats for them.
+const HP_SCALERS: &[(Preference, Preference)] = &[
+    (Preference::HuTao, Preference::Pyro),
+    (Preference::SangonomiyaKokomi, Preference::Hydro),
+];
+const DEF_SCALERS: &[(Preference, Preference)] = &[
+    (Preference::Noelle, Preference::Geo),
+    (Preference::Albedo, Preference::Geo),
+    (Preference::AratakiItto, Preference::Geo),
+    (Preference::Gorou, Preference::Geo),
+];
+
+// All 2 + 2 sets. Their names and preferences are made only once.
+pub fn two_plus_two() -> &'static [Artifact] {
+    static SETS: OnceLock<Vec<Artifact>> = OnceLock::new();
+    SETS.get_or_init(|| {
+        let two_piece = two_piece();
+        let mut result = Vec::new();
+        for (i, a) in two_piece.iter().enumerate() {
+            // the same set twice is not a 2 + 2 set
+            for b in two_piece[i + 1..].iter() {
+                result.extend(compose(a, b));
+            }
+        }
+        result
+    })
+}
+
+// Preferences of characters who prefer both of `a` and `b`. Empty lists mean
+// all characters, and `None` means no characters.
+fn intersection(a: &[Preference], b: &[Preference]) -> Option<Vec<Preference>> {
+    if a.is_empty() {
+        Some(b.to_vec())
+    } else if b.is_empty() {
+        Some(a.to_vec())
+    } else {
+        let x: Vec<Preference> = a.iter().filter(|p| b.contains(p)).cloned().collect();
+        if x.is_empty() { None } else { Some(x) }
+    }
+}
+
+// characters of `scalers` who prefer all of `lists`
+fn scalers(scalers: &[(Preference, Preference)], lists: &[&[Preference]]) -> Vec<Preference> {
+    scalers.iter()
+        .filter(|(name, vision)| lists.iter().all(|l| l.is_empty() || l.contains(name) || l.contains(vision)))
+        .map(|(name, _)| *name)
+        .collect()
+}
+
+fn leak_name(name: String) -> &'static str {
+    Box::leak(name.into_boxed_str())
+}
+
+fn leak_preference(preference: Vec<Preference>) -> &'static [Preference] {
+    Box::leak(preference.into_boxed_slice())
+}
+
+// Combine two 2 piece bonuses. The first set has ATK substats and is preferred
+// by characters who prefer both of them (none, e.g. Pyro DMG and Cryo DMG).
+// The others have HP or DEF substats for the HP or DEF scalers among them.
+// Names and preferences are leaked, so this should be called through
+// `two_plus_two`.
+fn compose(a: &Artifact, b: &Artifact) -> Vec<Artifact> {
+    let name = format!("{} + {}", a.name, b.name);
+    let bonus = |name: String, preference: &'static [Preference], substats: SubstatRolls| {
+        let mut ar = Artifact::default()
+            .name(leak_name(name))
+            .version(if a.version > b.version { a.version } else { b.version })
+            .preference(preference)
+            .substats(substats);
+        ar.add_bonus(a).add_bonus(b);
+        ar
+    };
+    let mut result = Vec::new();
+    if let Some(preference) = intersection(a.preference, b.preference) {
+        result.push(bonus(name.clone(), leak_preference(preference), SubstatRolls::new().atk(14.4).cr(21.6)));
+    }
+    let hp = scalers(HP_SCALERS, &[a.preference, b.preference]);
+    if !hp.is_empty() {
+        result.push(bonus(format!("{} (HP)", name), leak_preference(hp), SubstatRolls::new().hp(14.4).cr(21.6)));
+    }
+    let def = scalers(DEF_SCALERS, &[a.preference, b.preference]);
+    if !def.is_empty() {
+        result.push(bonus(format!("{} (DEF)", name), leak_preference(def), SubstatRolls::new().def(14.4).cr(21.6)));
+    }
+    result
+}
+
+// five artifacts of different sets
+pub fn rainbow() -> Vec<Artifact> {
+    use Preference::*;
+    vec![
+    Artifact::default()
+        .name("Rainbow")
+        .version(1.0)
+        .preference(&[])
+        .substats(SubstatRolls::new().atk(14.4).cr(21.6)),
+    Artifact::default()
+        .name("Rainbow (HP)")
+        .version(1.0)
+        .preference(&[HuTao, SangonomiyaKokomi])
+        .substats(SubstatRolls::new().hp(14.4).cr(21.6)),
+    Artifact::default()
+        .name("Rainbow (DEF)")
+        .version(1.0)
+        .preference(&[Noelle, Albedo, AratakiItto, Gorou])
+        .substats(SubstatRolls::new().def(14.4).cr(21.6)),
+    ]
+}
+
+pub fn four_piece() -> Vec<(Artifact, ArtifactUnion)> {
     vec![
-    // (Gfelm::record(), ArtifactUnion::Gfelm(Gfelm)),
     (Bcpf::record(), ArtifactUnion::Bcpf(Bcpf)),
     (ThunderingFury::record(), ArtifactUnion::ThunderingFury(ThunderingFury::new())),
     (ViridescentVenerer::record(), ArtifactUnion::ViridescentVenerer(ViridescentVenerer::new())),
@@ -153,10 +285,6 @@ pub fn all() -> Vec<(Artifact, ArtifactUnion)> {
     (Thundersoother::record(), ArtifactUnion::Thundersoother(Thundersoother::new())),
     (Lavawalker::record(), ArtifactUnion::Lavawalker(Lavawalker::new())),
     (LavawalkerHp::record(), ArtifactUnion::LavawalkerHp(LavawalkerHp::new())),
-    (Gfelm::record(), ArtifactUnion::Gfelm(Gfelm)),
-    (Gfelm1::record(), ArtifactUnion::Gfelm1(Gfelm1)),
-    (Gfelm2::record(), ArtifactUnion::Gfelm2(Gfelm2)),
-    (GfelmHpCr::record(), ArtifactUnion::GfelmHpCr(GfelmHpCr)),
     (BlizzardStrayer::record(), ArtifactUnion::BlizzardStrayer(BlizzardStrayer::new())),
     (HeartOfDepth::record(), ArtifactUnion::HeartOfDepth(HeartOfDepth::new())),
     (GlacierAndSnowfield::record(), ArtifactUnion::GlacierAndSnowfield(GlacierAndSnowfield::new())),
@@ -165,7 +293,6 @@ pub fn all() -> Vec<(Artifact, ArtifactUnion)> {
     (TenacityOfTheMillelithHP::record(), ArtifactUnion::TenacityOfTheMillelithHP(TenacityOfTheMillelithHP::new())),
     (TenacityOfTheMillelithDEF::record(), ArtifactUnion::TenacityOfTheMillelithDEF(TenacityOfTheMillelithDEF::new())),
     (ShimenawasReminiscence::record(), ArtifactUnion::ShimenawasReminiscence(ShimenawasReminiscence::new())),
-    (GfShimenawa::record(), ArtifactUnion::GfShimenawa(GfShimenawa)),
     (EmblemOfSeveredFate::record(), ArtifactUnion::EmblemOfSeveredFate(EmblemOfSeveredFate::new())),
     (EmblemOfSeveredFateER::record(), ArtifactUnion::EmblemOfSeveredFateER(EmblemOfSeveredFateER::new())),
     (HuskOfOpulentDreams::record(), ArtifactUnion::HuskOfOpulentDreams(HuskOfOpulentDreams::new())),
@@ -175,6 +302,14 @@ pub fn all() -> Vec<(Artifact, ArtifactUnion)> {
     ]
 }
 
+// 2 + 2 piece sets and rainbow sets have no effects other than stats.
+#[derive(Debug)]
+pub struct TwoPiece;
+
+impl Timeline for TwoPiece {}
+
+impl WeaponAttack for TwoPiece {}
+
 #[derive(Debug)]
 pub struct Bcpf;
 
@@ -183,13 +318,19 @@ impl Timeline for Bcpf {}
 impl WeaponAttack for Bcpf {}
 
 impl Bcpf {
-    pub fn record() -> Artifact {
+    pub fn two_piece() -> Artifact {
         Artifact::default()
-            .name("BCPF Physical 50")
+            .name("Bloodstained Chivalry")
             .version(1.0)
             .preference(&[Preference::Physical])
+            .physical_dmg(25.0)
+    }
+
+    pub fn record() -> Artifact {
+        Self::two_piece()
+            .name("BCPF Physical 50")
             .physical_dmg(50.0)
-            .atk(SCORE.atk(40.0)).cr(SCORE.cr(60.0))
+            .substats(SubstatRolls::new().atk(14.4).cr(21.6))
     }
 }
 
@@ -203,18 +344,24 @@ impl ThunderingFury {
         }
     }
 
-    pub fn record() -> Artifact {
+    pub fn two_piece() -> Artifact {
         Artifact::default()
             .name("Thundering Fury")
             .version(1.0)
             .preference(&[Preference::Electro])
             .electro_dmg(15.0)
+    }
+
+    pub fn record() -> Artifact {
+        Self::two_piece()
             .transformative_bonus(40.0)
-            .atk(SCORE.atk(40.0)).cr(SCORE.cr(60.0))
+            .substats(SubstatRolls::new().atk(14.4).cr(21.6))
     }
 }
 
 impl Timeline for ThunderingFury {
+    fn accelerates(&self) -> bool { true }
+
     fn accelerate(&mut self, field_energy: &mut Vec<FieldEnergy>, event: &CharacterAction, state: &mut ActionState, data: &CharacterData) -> () {
         if data.character.name.contains("Lisa") ||
            data.character.name.contains("Yae Miko (C2)") ||
@@ -233,30 +380,26 @@ impl WeaponAttack for ThunderingFury {}
 
 #[derive(Debug)]
 pub struct ViridescentVenerer {
-    pyro: f32,
-    hydro: f32,
-    electro: f32,
-    cryo: f32,
 }
 
 impl ViridescentVenerer {
     pub fn new() -> Self {
         Self {
-            pyro: -1.,
-            hydro: -1.,
-            electro: -1.,
-            cryo: -1.,
         }
     }
 
-    pub fn record() -> Artifact {
+    pub fn two_piece() -> Artifact {
         Artifact::default()
             .name("Viridescent Venerer")
             .version(1.0)
             .preference(&[Preference::Anemo])
             .anemo_dmg(15.)
+    }
+
+    pub fn record() -> Artifact {
+        Self::two_piece()
             .transformative_bonus(60.)
-            .atk(SCORE.atk(40.)).cr(SCORE.cr(60.))
+            .substats(SubstatRolls::new().atk(14.4).cr(21.6))
     }
 }
 
@@ -266,57 +409,13 @@ impl WeaponAttack for ViridescentVenerer {
     fn modify(&mut self, action_state: &ActionState, data: &CharacterData, attack: &mut Attack, state: &mut State, enemy: &mut Enemy) -> () {
         if data.idx == attack.idx && enemy.trigger_er(&attack.element.aura).is_swirl() {
             match &enemy.aura.aura {
-                Vision::Pyro => {
-                    if self.pyro < 0. {
-                        enemy.debuff.pyro += 40.;
-                    }
-                    self.pyro = attack.time;
-                },
-                Vision::Hydro => {
-                    if self.hydro < 0. {
-                        enemy.debuff.hydro += 40.;
-                    }
-                    self.hydro = attack.time;
-                },
-                Vision::Electro => {
-                    if self.electro < 0. {
-                        enemy.debuff.electro += 40.;
-                    }
-                    self.electro = attack.time;
-                },
-                Vision::Cryo => {
-                    if self.cryo < 0. {
-                        enemy.debuff.cryo += 40.;
-                    }
-                    self.cryo = attack.time;
-                },
+                aura @ Vision::Pyro |
+                aura @ Vision::Hydro |
+                aura @ Vision::Electro |
+                aura @ Vision::Cryo => enemy.apply_debuff(attack.time, Debuff::new("Viridescent Venerer", DebuffStat::Res(*aura), 40., attack.time, 10.)),
                 _ => (),
             }
         }
-        // check expire
-        if self.pyro >= 0. && attack.time - self.pyro > 10. {
-            enemy.debuff.pyro -= 40.;
-            self.pyro = -1.;
-        }
-        if self.hydro >= 0. && attack.time - self.hydro > 10. {
-            enemy.debuff.hydro -= 40.;
-            self.hydro = -1.;
-        }
-        if self.electro >= 0. && attack.time - self.electro > 10. {
-            enemy.debuff.electro -= 40.;
-            self.electro = -1.;
-        }
-        if self.cryo >= 0. && attack.time - self.cryo > 10. {
-            enemy.debuff.cryo -= 40.;
-            self.cryo = -1.;
-        }
-    }
-
-    fn reset_modify(&mut self) -> () {
-        self.pyro = -1.;
-        self.hydro = -1.;
-        self.electro = -1.;
-        self.cryo = -1.;
     }
 }
 
@@ -329,13 +428,9 @@ impl VVem {
     }
 
     pub fn record() -> Artifact {
-        Artifact::default()
+        ViridescentVenerer::record()
             .name("Viridescent Venerer (EM)")
-            .version(1.0)
-            .preference(&[Preference::Anemo])
-            .anemo_dmg(15.0)
-            .transformative_bonus(60.0)
-            .atk(SCORE.atk(10.0)).cr(SCORE.cr(10.0)).em(SCORE.em(80.0))
+            .substats(SubstatRolls::new().atk(3.6).cr(3.6).em(28.8))
     }
 }
 
@@ -345,10 +440,6 @@ impl WeaponAttack for VVem {
     fn modify(&mut self, action_state: &ActionState, data: &CharacterData, attack: &mut Attack, state: &mut State, enemy: &mut Enemy) -> () {
         self.0.modify(action_state, data, attack, state, enemy);
     }
-
-    fn reset_modify(&mut self) -> () {
-        self.0.reset_modify();
-    }
 }
 
 #[derive(Debug)]
@@ -369,13 +460,17 @@ impl ArchaicPetra {
         }
     }
 
-    pub fn record() -> Artifact {
+    pub fn two_piece() -> Artifact {
         Artifact::default()
             .name("Archaic Petra")
             .version(1.0)
             .preference(&[Preference::Geo])
             .geo_dmg(15.0)
-            .atk(SCORE.atk(40.0)).cr(SCORE.cr(60.0))
+    }
+
+    pub fn record() -> Artifact {
+        Self::two_piece()
+            .substats(SubstatRolls::new().atk(14.4).cr(21.6))
     }
 }
 
@@ -428,14 +523,18 @@ impl CrimsonWitchOfFlames {
         }
     }
 
-    pub fn record() -> Artifact {
+    pub fn two_piece() -> Artifact {
         Artifact::default()
             .name("Crimson Witch of Flames")
             .version(1.0)
             .preference(&[Preference::Pyro])
             .pyro_dmg(15.0)
+    }
+
+    pub fn record() -> Artifact {
+        Self::two_piece()
             .amplifying_bonus(15.0).transformative_bonus(40.0)
-            .atk(SCORE.atk(40.0)).cr(SCORE.cr(60.0))
+            .substats(SubstatRolls::new().atk(14.4).cr(21.6))
     }
 }
 
@@ -475,13 +574,10 @@ impl CrimsonWitchOfFlamesHp {
     }
 
     pub fn record() -> Artifact {
-        Artifact::default()
+        CrimsonWitchOfFlames::record()
             .name("Crimson Witch of Flames (HP)")
-            .version(1.0)
             .preference(&[Preference::HuTao])
-            .pyro_dmg(15.0)
-            .amplifying_bonus(15.0).transformative_bonus(40.0)
-            .hp(SCORE.hp(40.0)).cr(SCORE.cr(60.0))
+            .substats(SubstatRolls::new().hp(14.4).cr(21.6))
     }
 }
 
@@ -507,17 +603,22 @@ impl NoblesseOblige {
         Self { time: -99., }
     }
 
-    pub fn record() -> Artifact {
-        use Preference::*;
+    pub fn two_piece() -> Artifact {
         Artifact::default()
             .name("Noblesse Oblige")
             .version(1.0)
+            .preference(&[])
+            .burst_dmg(20.0)
+    }
+
+    pub fn record() -> Artifact {
+        use Preference::*;
+        Self::two_piece()
             .preference(&[
                 Chongyun, Keqing, Ningguang, Amber, Diluc, Zhongli, Albedo, Aloy,
                 Kaeya, Mona, Bennett, Ganyu, Rosaria
                 ])
-            .burst_dmg(20.0)
-            .atk(SCORE.atk(40.0)).cr(SCORE.cr(60.0))
+            .substats(SubstatRolls::new().atk(14.4).cr(21.6))
     }
 }
 
@@ -547,13 +648,19 @@ impl GladiatorsFinale {
         Self {}
     }
 
-    pub fn record() -> Artifact {
+    pub fn two_piece() -> Artifact {
         Artifact::default()
             .name("Gladiator's Finale")
             .version(1.0)
+            .preference(&[])
+            .atk(18.0)
+    }
+
+    pub fn record() -> Artifact {
+        Self::two_piece()
             .preference(&[Preference::Melee])
             .na_dmg(35.0)
-            .atk(18.0 + SCORE.atk(40.0)).cr(SCORE.cr(60.0))
+            .substats(SubstatRolls::new().atk(14.4).cr(21.6))
     }
 }
 
@@ -570,13 +677,10 @@ impl GladiatorsFinaleDef {
     }
 
     pub fn record() -> Artifact {
-        Artifact::default()
+        GladiatorsFinale::record()
             .name("Gladiator's Finale (DEF)")
-            .version(1.0)
             .preference(&[Preference::Noelle, Preference::Albedo, Preference::AratakiItto])
-            .atk(18.0)
-            .na_dmg(35.0)
-            .def(SCORE.def(40.0)).cr(SCORE.cr(60.0))
+            .substats(SubstatRolls::new().def(14.4).cr(21.6))
     }
 }
 
@@ -592,14 +696,19 @@ impl Timeline for WanderersTroupe {}
 impl WeaponAttack for WanderersTroupe {}
 
 impl WanderersTroupe {
-    pub fn record() -> Artifact {
+    pub fn two_piece() -> Artifact {
         Artifact::default()
             .name("Wanderer's Troupe")
             .version(1.0)
-            .preference(&[Preference::Ganyu])
+            .preference(&[])
             .em(80.0)
+    }
+
+    pub fn record() -> Artifact {
+        Self::two_piece()
+            .preference(&[Preference::Ganyu])
             .ca_dmg(35.0)
-            .atk(SCORE.atk(40.0)).cr(SCORE.cr(60.0))
+            .substats(SubstatRolls::new().atk(14.4).cr(21.6))
     }
 }
 
@@ -611,18 +720,24 @@ impl Timeline for RetracingBolide {}
 impl WeaponAttack for RetracingBolide {}
 
 impl RetracingBolide {
-    pub fn record() -> Artifact {
-        use Preference::*;
+    // 2 Piece: Increases Shield Strength by 35%.
+    pub fn two_piece() -> Artifact {
         Artifact::default()
             .name("Retracing Bolide")
             .version(1.0)
+            .preference(&[])
+    }
+
+    pub fn record() -> Artifact {
+        use Preference::*;
+        Self::two_piece()
             .preference(&[
                 Diluc, Klee, Razor, Keqing, Noelle,
                 Tartaglia, Xinyan, Ganyu, Xiao, HuTao, Yanfei, Eula,
                 Ayaka, Yoimiya, Aloy
                 ])
             .na_dmg(40.0).ca_dmg(40.0)
-            .atk(SCORE.atk(40.0)).cr(SCORE.cr(60.0))
+            .substats(SubstatRolls::new().atk(14.4).cr(21.6))
     }
 }
 
@@ -635,12 +750,10 @@ impl WeaponAttack for RetracingBolideDef {}
 
 impl RetracingBolideDef {
     pub fn record() -> Artifact {
-        Artifact::default()
+        RetracingBolide::record()
             .name("Retracing Bolide (DEF)")
-            .version(1.0)
             .preference(&[Preference::Noelle,Preference::AratakiItto])
-            .na_dmg(40.0).ca_dmg(40.0)
-            .def(SCORE.def(40.0)).cr(SCORE.cr(60.0))
+            .substats(SubstatRolls::new().def(14.4).cr(21.6))
     }
 }
 
@@ -653,12 +766,10 @@ impl WeaponAttack for RetracingBolideHP {}
 
 impl RetracingBolideHP {
     pub fn record() -> Artifact {
-        Artifact::default()
+        RetracingBolide::record()
             .name("Retracing Bolide (HP)")
-            .version(1.0)
             .preference(&[Preference::HuTao])
-            .na_dmg(40.0).ca_dmg(40.0)
-            .hp(SCORE.hp(40.0)).cr(SCORE.cr(60.0))
+            .substats(SubstatRolls::new().hp(14.4).cr(21.6))
     }
 }
 
@@ -666,13 +777,17 @@ impl RetracingBolideHP {
 pub struct Thundersoother {}
 
 impl Thundersoother {
-    pub fn record() -> Artifact {
+    // 2 Piece: Increases Electro RES by 40%.
+    pub fn two_piece() -> Artifact {
         Artifact::default()
             .name("Thundersoother")
             .version(1.0)
             .preference(&[Preference::Electro])
-            .atk(SCORE.atk(40.0))
-            .cr(SCORE.cr(60.0))
+    }
+
+    pub fn record() -> Artifact {
+        Self::two_piece()
+            .substats(SubstatRolls::new().atk(14.4).cr(21.6))
     }
 
     pub fn new() -> Self {
@@ -695,12 +810,17 @@ impl WeaponAttack for Thundersoother {
 pub struct Lavawalker {}
 
 impl Lavawalker {
-    pub fn record() -> Artifact {
+    // 2 Piece: Increases Pyro RES by 40%.
+    pub fn two_piece() -> Artifact {
         Artifact::default()
             .name("Lavawalker")
             .version(1.0)
             .preference(&[Preference::Pyro])
-            .atk(SCORE.atk(40.0)).cr(SCORE.cr(60.0))
+    }
+
+    pub fn record() -> Artifact {
+        Self::two_piece()
+            .substats(SubstatRolls::new().atk(14.4).cr(21.6))
     }
 
     pub fn new() -> Self {
@@ -725,12 +845,10 @@ pub struct LavawalkerHp {
 
 impl LavawalkerHp {
     pub fn record() -> Artifact {
-        Artifact::default()
+        Lavawalker::record()
             .name("Lavawalker (HP)")
-            .version(1.0)
             .preference(&[Preference::HuTao])
-            .hp(SCORE.hp(40.0))
-            .cr(SCORE.cr(60.0))
+            .substats(SubstatRolls::new().hp(14.4).cr(21.6))
     }
 
     pub fn new() -> Self {
@@ -749,89 +867,22 @@ impl WeaponAttack for LavawalkerHp {
     }
 }
 
-#[derive(Debug)]
-pub struct Gfelm;
-
-impl Timeline for Gfelm {}
-
-impl WeaponAttack for Gfelm {}
-
-impl Gfelm {
-    pub fn record() -> Artifact {
-        Artifact::default()
-            .name("GFElem ATK 18 DMG 15")
-            .version(1.0)
-            .preference(&[])
-            .elemental_dmg(15.0)
-            .atk(18.0 + SCORE.atk(40.0)).cr(SCORE.cr(60.0))
-    }
-}
-
-#[derive(Debug)]
-pub struct Gfelm1;
-
-impl Timeline for Gfelm1 {}
-
-impl WeaponAttack for Gfelm1 {}
-
-impl Gfelm1 {
-    pub fn record() -> Artifact {
-        Artifact::default()
-            .name("GFElem ATK88 CR46 Em280 DMG15")
-            .version(1.0)
-            .preference(&[])
-            .elemental_dmg(15.0)
-            .atk(18.0 + SCORE.atk(33.3333)).cr(SCORE.cr(33.3333)).em(SCORE.em(33.3333))
-    }
-}
-
-#[derive(Debug)]
-pub struct Gfelm2;
-
-impl Timeline for Gfelm2 {}
-
-impl WeaponAttack for Gfelm2 {}
-
-impl Gfelm2 {
-    pub fn record() -> Artifact {
-        Artifact::default()
-            .name("GFElem ATK70 CR35 Em420 DMG15")
-            .version(1.0)
-            .preference(&[])
-            .elemental_dmg(15.0)
-            .atk(18.0 + SCORE.atk(25.0)).cr(SCORE.cr(25.0)).em(SCORE.em(50.0))
-    }
-}
-
-#[derive(Debug)]
-pub struct GfelmHpCr;
-
-impl Timeline for GfelmHpCr {}
-
-impl WeaponAttack for GfelmHpCr {}
-
-impl GfelmHpCr {
-    pub fn record() -> Artifact {
-        Artifact::default()
-            .name("GFE HP 105 ATK 105 DMG15")
-            .version(1.0)
-            .preference(&[Preference::SangonomiyaKokomi])
-            .elemental_dmg(15.0)
-            .hp(SCORE.hp(50.0)).atk(SCORE.atk(50.0))
-    }
-}
-
 #[derive(Debug)]
 pub struct BlizzardStrayer {}
 
 impl BlizzardStrayer {
-    pub fn record() -> Artifact {
+    pub fn two_piece() -> Artifact {
         Artifact::default()
             .name("Blizzard Strayer")
             .version(1.2)
-            .preference(&[Preference::Cryo, Preference::Hydro])
+            .preference(&[Preference::Cryo])
             .cryo_dmg(15.0)
-            .atk(SCORE.atk(40.0)).cr(SCORE.cr(60.0))
+    }
+
+    pub fn record() -> Artifact {
+        Self::two_piece()
+            .preference(&[Preference::Cryo, Preference::Hydro])
+            .substats(SubstatRolls::new().atk(14.4).cr(21.6))
     }
 
     pub fn new() -> Self {
@@ -866,13 +917,17 @@ impl HeartOfDepth {
         }
     }
 
-    pub fn record() -> Artifact {
+    pub fn two_piece() -> Artifact {
         Artifact::default()
             .name("Heart of Depth")
             .version(1.2)
             .preference(&[Preference::Hydro])
             .hydro_dmg(15.0)
-            .atk(SCORE.atk(40.0)).cr(SCORE.cr(60.0))
+    }
+
+    pub fn record() -> Artifact {
+        Self::two_piece()
+            .substats(SubstatRolls::new().atk(14.4).cr(21.6))
     }
 }
 
@@ -906,14 +961,18 @@ impl GlacierAndSnowfield {
         }
     }
 
-    pub fn record() -> Artifact {
+    pub fn two_piece() -> Artifact {
         Artifact::default()
             .name("Glacier and Snowfield")
             .version(99.0)
             .preference(&[Preference::Cryo])
             .cryo_dmg(15.0)
+    }
+
+    pub fn record() -> Artifact {
+        Self::two_piece()
             .amplifying_bonus(15.0).transformative_bonus(100.0)
-            .atk(SCORE.atk(40.0)).cr(SCORE.cr(60.0))
+            .substats(SubstatRolls::new().atk(14.4).cr(21.6))
     }
 }
 
@@ -944,13 +1003,17 @@ impl PaleFlame {
         }
     }
 
-    pub fn record() -> Artifact {
+    pub fn two_piece() -> Artifact {
         Artifact::default()
             .name("Pale Flame")
             .version(1.5)
             .preference(&[Preference::Physical])
             .physical_dmg(25.0)
-            .atk(SCORE.atk(40.0)).cr(SCORE.cr(60.0))
+    }
+
+    pub fn record() -> Artifact {
+        Self::two_piece()
+            .substats(SubstatRolls::new().atk(14.4).cr(21.6))
     }
 }
 
@@ -994,18 +1057,24 @@ impl TenacityOfTheMillelith {
         Self { time: -99. }
     }
 
-    pub fn record() -> Artifact {
+    pub fn two_piece() -> Artifact {
         use Preference::*;
         Artifact::default()
             .name("Tenacity of the Millelith")
             .version(1.5)
+            .preference(&[HuTao, Zhongli, SangonomiyaKokomi])
+            .hp(20.0)
+    }
+
+    pub fn record() -> Artifact {
+        use Preference::*;
+        Self::two_piece()
             .preference(&[
                 Fischl, Qiqi,
                 Zhongli, Xinyan,
                 RaidenShogun,
                 ])
-            .hp(20.0)
-            .atk(SCORE.atk(40.0)).cr(SCORE.cr(60.0))
+            .substats(SubstatRolls::new().atk(14.4).cr(21.6))
     }
 }
 
@@ -1036,12 +1105,10 @@ impl TenacityOfTheMillelithHP {
     }
 
     pub fn record() -> Artifact {
-        Artifact::default()
+        TenacityOfTheMillelith::record()
             .name("Tenacity of the Millelith (HP)")
-            .version(1.5)
             .preference(&[Preference::SangonomiyaKokomi ])
-            .hp(20. + SCORE.hp(50.))
-            .atk(SCORE.atk(50.))
+            .substats(SubstatRolls::new().hp(18.).atk(18.))
     }
 }
 
@@ -1066,13 +1133,10 @@ impl TenacityOfTheMillelithDEF {
     }
 
     pub fn record() -> Artifact {
-        Artifact::default()
+        TenacityOfTheMillelith::record()
             .name("Tenacity of the Millelith (DEF)")
-            .version(1.5)
             .preference(&[Preference::Albedo ])
-            .hp(20.)
-            .def(SCORE.def(40.))
-            .cr(SCORE.cr(60.))
+            .substats(SubstatRolls::new().def(14.4).cr(21.6))
     }
 }
 
@@ -1105,22 +1169,30 @@ impl ShimenawasReminiscence {
         }
     }
 
-    pub fn record() -> Artifact {
-        use Preference::*;
+    pub fn two_piece() -> Artifact {
         Artifact::default()
             .name("Shimenawa's Reminiscence")
             .version(2.0)
+            .preference(&[])
+            .atk(18.0)
+    }
+
+    pub fn record() -> Artifact {
+        use Preference::*;
+        Self::two_piece()
             // copy of "Retracing Bolide"
             .preference(&[
                 Diluc, Klee, Razor, Keqing, Noelle,
                 Tartaglia, Xinyan, Ganyu, Xiao, HuTao, Yanfei, Eula,
                 Ayaka, Yoimiya, Aloy
             ])
-            .atk(18.0 + SCORE.atk(40.0)).cr(SCORE.cr(60.0))
+            .substats(SubstatRolls::new().atk(14.4).cr(21.6))
     }
 }
 
 impl Timeline for ShimenawasReminiscence {
+    fn accelerates(&self) -> bool { true }
+
     fn accelerate(&mut self, field_energy: &mut Vec<FieldEnergy>, event: &CharacterAction, state: &mut ActionState, data: &CharacterData) -> () {
         match (state.energy >= 15., event) {
             (true, CharacterAction::PressSkill) |
@@ -1150,23 +1222,6 @@ impl WeaponAttack for ShimenawasReminiscence {
     }
 }
 
-#[derive(Debug)]
-pub struct GfShimenawa;
-
-impl Timeline for GfShimenawa {}
-
-impl WeaponAttack for GfShimenawa {}
-
-impl GfShimenawa {
-    pub fn record() -> Artifact {
-        Artifact::default()
-            .name("GFShimenawa ATK 36")
-            .version(2.0)
-            .preference(&[])
-            .atk(36.0 + SCORE.atk(40.0)).cr(SCORE.cr(60.0))
-    }
-}
-
 #[derive(Debug)]
 pub struct EmblemOfSeveredFate {
 }
@@ -1174,17 +1229,22 @@ pub struct EmblemOfSeveredFate {
 // 4 Piece: Increases Elemental Burst DMG by 25% of Energy Recharge. A maximum
 // 75% DMG increase can be obtained in this way.
 impl EmblemOfSeveredFate {
-    pub fn record() -> Artifact {
-        use Preference::*;
+    pub fn two_piece() -> Artifact {
         Artifact::default()
             .name("Emblem of Severed Fate")
             .version(2.0)
+            .preference(&[])
+            .er(20.0)
+    }
+
+    pub fn record() -> Artifact {
+        use Preference::*;
+        Self::two_piece()
             .preference(&[
                 Beidou, Lisa, Xingqiu, Xiangling, Diona, Eula,
                 Ayaka, TravelerElectro, RaidenShogun, KujouSara, Thoma
             ])
-            .er(20.0)
-            .atk(SCORE.atk(40.0)).cr(SCORE.cr(60.0))
+            .substats(SubstatRolls::new().atk(14.4).cr(21.6))
     }
 
     pub fn new() -> Self {
@@ -1219,16 +1279,9 @@ pub struct EmblemOfSeveredFateER {}
 
 impl EmblemOfSeveredFateER {
     pub fn record() -> Artifact {
-        use Preference::*;
-        Artifact::default()
+        EmblemOfSeveredFate::record()
             .name("EoSF ATK70 CR47 ER77")
-            .version(2.0)
-            .preference(&[
-                Beidou, Lisa, Xingqiu, Xiangling, Diona, Eula,
-                Ayaka, TravelerElectro, RaidenShogun, KujouSara, Thoma
-            ])
-            .er(20.0 + SCORE.er(33.3333))
-            .atk(SCORE.atk(33.3333)).cr(SCORE.cr(33.3333))
+            .substats(SubstatRolls::new().er(12.).atk(12.).cr(12.))
     }
 
     pub fn new() -> Self {
@@ -1257,6 +1310,8 @@ impl WeaponAttack for EmblemOfSeveredFateER {
 #[derive(Debug)]
 pub struct HuskOfOpulentDreams {
     stack: f32,
+    // stacks at the beginning, which are given by `set_stacks`
+    start: f32,
     time: f32,
 }
 
@@ -1265,16 +1320,23 @@ impl HuskOfOpulentDreams {
         Self {
             time: -99.,
             stack: 2.,
+            start: 2.,
         }
     }
 
-    pub fn record() -> Artifact {
+    pub fn two_piece() -> Artifact {
         use Preference::*;
         Artifact::default()
-            .name("Husk of Opulent Dreams (start 2s)")
+            .name("Husk of Opulent Dreams")
             .version(2.3)
             .preference(&[Noelle, Albedo, AratakiItto, Gorou ])
-            .def(30. + SCORE.def(40.0)).cr(SCORE.cr(60.0))
+            .def(30.)
+    }
+
+    pub fn record() -> Artifact {
+        Self::two_piece()
+            .name("Husk of Opulent Dreams (start 2s)")
+            .substats(SubstatRolls::new().def(14.4).cr(21.6))
     }
 }
 
@@ -1298,9 +1360,14 @@ impl WeaponAttack for HuskOfOpulentDreams {
         state.geo_dmg += 6. * self.stack;
     }
 
+    fn set_stacks(&mut self, stacks: f32) -> () {
+        self.start = stacks.clamp(0., 4.);
+        self.stack = self.start;
+    }
+
     fn reset_modify(&mut self) -> () {
         self.time = -99.;
-        self.stack = 2.;
+        self.stack = self.start;
     }
 }
 
@@ -1313,13 +1380,11 @@ impl HuskOfOpulentDreamsAlbedo {
     }
 
     pub fn record() -> Artifact {
-        Artifact::default()
+        HuskOfOpulentDreams::record()
             .name("Husk of Opulent Dreams (DEF goblet)")
-            .version(2.3)
             .preference(&[Preference::Albedo])
-            .def(30. + 58.3 + SCORE.def(40.0))
+            .def(30. + 58.3)
             .geo_dmg(-46.6)
-            .cr(SCORE.cr(60.0))
     }
 }
 
@@ -1359,14 +1424,20 @@ impl OceanHuedClam {
         }
     }
 
+    pub fn two_piece() -> Artifact {
+        Artifact::default()
+            .name("Ocean-Hued Clam")
+            .version(2.3)
+            .preference(&[])
+            .healing_bonus(15.0)
+    }
+
     pub fn record() -> Artifact {
         use Preference::*;
-        Artifact::default()
+        Self::two_piece()
             .name("Ocean-Hued Clam (7k heal)")
-            .version(2.3)
             .preference(&[Bennett, Barbara, Qiqi, Jean, Noelle, Diona, Sayu, ])
-            .atk(SCORE.atk(40.0))
-            .cr(SCORE.cr(60.0))
+            .substats(SubstatRolls::new().atk(14.4).cr(21.6))
     }
 }
 
@@ -1384,6 +1455,7 @@ impl WeaponAttack for OceanHuedClam {
                 aura_application: false,
                 time,
                 idx: data.idx,
+                scaling: Scaling::Atk,
             });
         }
     }
@@ -1402,12 +1474,9 @@ impl OceanHuedClamKokomi {
     }
 
     pub fn record() -> Artifact {
-        Artifact::default()
-            .name("Ocean-Hued Clam (7k heal)")
-            .version(2.3)
+        OceanHuedClam::record()
             .preference(&[Preference::SangonomiyaKokomi])
-            .atk(SCORE.atk(50.0))
-            .hp(SCORE.hp(50.0))
+            .substats(SubstatRolls::new().atk(18.).hp(18.))
     }
 }
 
@@ -1492,6 +1561,60 @@ mod tests {
     //     assert_eq!(total_dmg, expect);
     // }
 
+    #[test]
+    fn compose_two_piece() {
+        let ar = &compose(&GladiatorsFinale::two_piece(), &ShimenawasReminiscence::two_piece())[0];
+        assert_eq!(ar.name, "Gladiator's Finale + Shimenawa's Reminiscence");
+        assert_eq!(ar.atk, 36.0);
+        assert_eq!(ar.version, 2.0);
+        assert!(ar.preference.is_empty());
+
+        let sets = compose(&GladiatorsFinale::two_piece(), &CrimsonWitchOfFlames::two_piece());
+        assert_eq!(sets[0].atk, 18.0);
+        assert_eq!(sets[0].pyro_dmg, 15.0);
+        assert_eq!(sets[0].preference, &[Preference::Pyro]);
+        assert_eq!(sets[0].substats.atk, 14.4);
+        // HP substats for Hu Tao
+        assert_eq!(sets[1].name, "Gladiator's Finale + Crimson Witch of Flames (HP)");
+        assert_eq!(sets[1].preference, &[Preference::HuTao]);
+        assert_eq!(sets[1].substats.hp, 14.4);
+        assert_eq!(sets[1].substats.atk, 0.);
+        assert_eq!(sets.len(), 2);
+
+        let ar = &compose(&Bcpf::two_piece(), &PaleFlame::two_piece())[0];
+        assert_eq!(ar.physical_dmg, 50.0);
+
+        assert!(compose(&CrimsonWitchOfFlames::two_piece(), &BlizzardStrayer::two_piece()).is_empty());
+
+        // the sets overlap in Hu Tao
+        let sets = compose(&TenacityOfTheMillelith::two_piece(), &CrimsonWitchOfFlames::two_piece());
+        assert!(sets.iter().any(|ar| ar.preference == [Preference::HuTao] && ar.substats.hp > 0.));
+        // Geo DEF scalers of Husk of Opulent Dreams
+        let sets = compose(&HuskOfOpulentDreams::two_piece(), &ArchaicPetra::two_piece());
+        assert_eq!(sets.len(), 1);
+        assert_eq!(sets[0].preference, &[Preference::Noelle, Preference::Albedo, Preference::AratakiItto, Preference::Gorou]);
+        assert_eq!(sets[0].substats.def, 14.4);
+    }
+
+    #[test]
+    fn two_plus_two_is_built_once() {
+        let a = two_plus_two();
+        let b = two_plus_two();
+        assert!(std::ptr::eq(a, b));
+        assert!(std::ptr::eq(a[0].name, b[0].name));
+    }
+
+    #[test]
+    fn four_piece_contains_two_piece() {
+        let two = CrimsonWitchOfFlames::two_piece();
+        let four = CrimsonWitchOfFlames::record();
+        assert_eq!(two.pyro_dmg, four.pyro_dmg);
+        assert_eq!(four.amplifying_bonus, 15.0);
+        assert_eq!(two.amplifying_bonus, 0.0);
+        let n = all().iter().filter(|(ar, _)| ar.name == "Rainbow").count();
+        assert_eq!(n, 1);
+    }
+
     #[test]
     fn invariance_1() {
         let mut history = testutil::history_2at02();
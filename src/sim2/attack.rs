@@ -3,6 +3,7 @@ use crate::sim2::timeline::ActionState;
 use crate::sim2::state::State;
 use crate::sim2::element::{ElementalGauge, ElementalReactionType, ElementalReaction};
 use crate::sim2::record::{CharacterData, Enemy};
+use crate::sim2::event::Event;
 
 #[derive(Debug)]
 pub struct DamageResult {
@@ -49,7 +50,7 @@ impl DamageResult {
 
     pub fn reaction(attack: Attack, state: &State, data: &CharacterData, enemy: &mut Enemy) -> Self {
         use ElementalReactionType::*;
-        let atk = attack.atk(state, data.character.name);
+        let atk = attack.atk(state);
         let bonus = attack.bonus(state);
         let crcd = state.CRCD();
         let multiplier = attack.multiplier(state);
@@ -81,7 +82,7 @@ impl DamageResult {
     }
 
     pub fn without_reaction(attack: Attack, state: &State, data: &CharacterData, enemy: &mut Enemy) -> Self {
-        let atk = attack.atk(state, data.character.name);
+        let atk = attack.atk(state);
         let bonus = attack.bonus(state);
         let crcd = state.CRCD();
         let multiplier = attack.multiplier(state);
@@ -115,6 +116,31 @@ impl DamageResultUtil for Vec<DamageResult> {
     }
 }
 
+// the stat that the multiplier of an `Attack` is applied to
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Scaling {
+    Atk,
+    Hp,
+    Def,
+    Em,
+    // ratios of the multiplier applied to each stat. for example,
+    // `Mixed { atk: 1.0, hp: 0.0, def: 0.5, em: 0.0 }` deals the multiplier of
+    // ATK plus a half of it of DEF.
+    Mixed { atk: f32, hp: f32, def: f32, em: f32 },
+}
+
+impl Scaling {
+    pub fn value(&self, state: &State) -> f32 {
+        match self {
+            Scaling::Atk => state.ATK(),
+            Scaling::Hp => state.HP(),
+            Scaling::Def => state.DEF(),
+            Scaling::Em => state.em,
+            Scaling::Mixed { atk, hp, def, em } => atk * state.ATK() + hp * state.HP() + def * state.DEF() + em * state.em,
+        }
+    }
+}
+
 #[derive(Debug)]
 pub struct Attack {
     // type of this `Attack`. For example, Xiangling's skill summons Guoba to
@@ -134,17 +160,16 @@ pub struct Attack {
     pub time: f32,
 
     pub idx: FieldCharacterIndex,
+
+    pub scaling: Scaling,
 }
 
+// stats of characters in records are at this level
+pub const CHARACTER_LEVEL: f32 = 90.0;
+
 impl Attack {
-    pub fn atk(&self, state: &State, name: &str) -> f32 {
-        state.flat_dmg + match (name, &self.kind) {
-            ("Yun Jin", DamageType::Skill) |
-            ("Gorou", DamageType::Burst) |
-            ("Albedo", DamageType::Skill) |
-            ("Noelle", DamageType::Skill) => state.DEF(),
-            _ => state.ATK(),
-        }
+    pub fn atk(&self, state: &State) -> f32 {
+        state.flat_dmg + self.scaling.value(state)
     }
 
     pub fn bonus(&self, state: &State) -> f32 {
@@ -155,9 +180,11 @@ impl Attack {
         self.multiplier / 100.0 * state.get_talent_bonus(&self.kind)
     }
 
+    // (Lc + 100) / ((Lc + 100) + (Le + 100) * (1 - def_down))
     pub fn defense(&self, enemy: &Enemy) -> f32 {
-        let def_down = 1.0 - enemy.def_down / 100.0;
-        enemy.level / (enemy.level * def_down + enemy.level)
+        let def_down = 1.0 - enemy.def_down(self.time) / 100.0;
+        let character = CHARACTER_LEVEL + 100.0;
+        character / (character + (enemy.level + 100.0) * def_down)
     }
 
     pub fn resistance(&self, enemy: &Enemy) -> f32 {
@@ -169,11 +196,14 @@ pub trait CharacterAttack {
     fn attack(&mut self, time: f32, event: &CharacterAction, data: &CharacterData, atk_queue: &mut Vec<Attack>, state: &mut State, enemy: &mut Enemy) -> () {
         use CharacterAction::*;
         match event {
-            StandStill => (),
+            StandStill |
+            Jump => (),
             Burst => self.burst(time, event, data, atk_queue, state, enemy),
             PressSkill => self.press(time, event, data, atk_queue, state, enemy),
             HoldSkill => self.hold(time, event, data, atk_queue, state, enemy),
             Ca(_) => self.ca(time, event, data, atk_queue, state, enemy),
+            Plunge => self.plunge(time, event, data, atk_queue, state, enemy),
+            Dash => self.dash(time, event, data, atk_queue, state, enemy),
             Na1(_) => self.na1(time, event, data, atk_queue, state, enemy),
             Na2(_) => self.na2(time, event, data, atk_queue, state, enemy),
             Na3(_) => self.na3(time, event, data, atk_queue, state, enemy),
@@ -193,9 +223,18 @@ pub trait CharacterAttack {
     fn na5(&mut self, time: f32, event: &CharacterAction, data: &CharacterData, atk_queue: &mut Vec<Attack>, state: &mut State, enemy: &mut Enemy) -> () {}
     fn na6(&mut self, time: f32, event: &CharacterAction, data: &CharacterData, atk_queue: &mut Vec<Attack>, state: &mut State, enemy: &mut Enemy) -> () {}
     fn ca(&mut self, time: f32, event: &CharacterAction, data: &CharacterData, atk_queue: &mut Vec<Attack>, state: &mut State, enemy: &mut Enemy) -> () {}
+    fn plunge(&mut self, time: f32, event: &CharacterAction, data: &CharacterData, atk_queue: &mut Vec<Attack>, state: &mut State, enemy: &mut Enemy) -> () {}
+    // dashes of some characters deal damage (e.g. Kamisato Art: Senho)
+    fn dash(&mut self, time: f32, event: &CharacterAction, data: &CharacterData, atk_queue: &mut Vec<Attack>, state: &mut State, enemy: &mut Enemy) -> () {}
 
+    // called after `attack` of all times and before any hit. `SkillHit`,
+    // `Crit` and `Reaction` events are delivered after this, so states set by
+    // them should be cleared in `reset_modify`.
     fn reset_attack(&mut self) -> () {}
 
+    // events of all members, including this character
+    fn event(&mut self, time: f32, event: &Event, data: &CharacterData) -> () {}
+
     // `ActionState` is the state of this character
     // `Attack` and `State` can be owned by this character or the others
     fn modify(&mut self, action_state: &ActionState, data: &CharacterData, attack: &mut Attack, state: &mut State, enemy: &mut Enemy) -> () {}
@@ -206,26 +245,37 @@ pub trait CharacterAttack {
 pub trait WeaponAttack {
     fn attack(&mut self, time: f32, event: &CharacterAction, data: &CharacterData, atk_queue: &mut Vec<Attack>, state: &mut State, enemy: &mut Enemy) -> () {}
 
+    // see `CharacterAttack::reset_attack`
     fn reset_attack(&mut self) -> () {}
 
+    fn event(&mut self, time: f32, event: &Event, data: &CharacterData) -> () {}
+
     fn modify(&mut self, action_state: &ActionState, data: &CharacterData, attack: &mut Attack, state: &mut State, enemy: &mut Enemy) -> () {}
 
+    // Start simulations with `stacks` stacks of the passive, e.g. those left
+    // by the previous rotation. `reset_modify` keeps them.
+    fn set_stacks(&mut self, stacks: f32) -> () {}
+
     fn reset_modify(&mut self) -> () {}
 }
 
+// The methods queue an `Attack` scaling with ATK, and return it so that the
+// caller can change it, e.g. `atk_queue.add_skill(..).scaling = Scaling::Def`.
 pub trait AtkQueue {
-    fn add_burst(&mut self, multiplier: f32, element: &'static ElementalGauge, time: f32, event: &CharacterAction, data: &CharacterData, state: &mut State) -> ();
-    fn add_skill(&mut self, multiplier: f32, element: &'static ElementalGauge, time: f32, event: &CharacterAction, data: &CharacterData, state: &mut State) -> ();
-    fn add_na(&mut self, multiplier: f32, element: &'static ElementalGauge, time: f32, event: &CharacterAction, data: &CharacterData, state: &mut State) -> ();
-    fn add_ca(&mut self, multiplier: f32, element: &'static ElementalGauge, time: f32, event: &CharacterAction, data: &CharacterData, state: &mut State) -> ();
-    fn apply_burst(&mut self, multiplier: f32, element: &'static ElementalGauge, time: f32, event: &CharacterAction, data: &CharacterData, state: &mut State) -> ();
-    fn apply_skill(&mut self, multiplier: f32, element: &'static ElementalGauge, time: f32, event: &CharacterAction, data: &CharacterData, state: &mut State) -> ();
-    fn apply_na(&mut self, multiplier: f32, element: &'static ElementalGauge, time: f32, event: &CharacterAction, data: &CharacterData, state: &mut State) -> ();
-    fn apply_ca(&mut self, multiplier: f32, element: &'static ElementalGauge, time: f32, event: &CharacterAction, data: &CharacterData, state: &mut State) -> ();
+    fn add_burst(&mut self, multiplier: f32, element: &'static ElementalGauge, time: f32, event: &CharacterAction, data: &CharacterData, state: &mut State) -> &mut Attack;
+    fn add_skill(&mut self, multiplier: f32, element: &'static ElementalGauge, time: f32, event: &CharacterAction, data: &CharacterData, state: &mut State) -> &mut Attack;
+    fn add_na(&mut self, multiplier: f32, element: &'static ElementalGauge, time: f32, event: &CharacterAction, data: &CharacterData, state: &mut State) -> &mut Attack;
+    fn add_ca(&mut self, multiplier: f32, element: &'static ElementalGauge, time: f32, event: &CharacterAction, data: &CharacterData, state: &mut State) -> &mut Attack;
+    fn add_plunge(&mut self, multiplier: f32, element: &'static ElementalGauge, time: f32, event: &CharacterAction, data: &CharacterData, state: &mut State) -> &mut Attack;
+    fn apply_burst(&mut self, multiplier: f32, element: &'static ElementalGauge, time: f32, event: &CharacterAction, data: &CharacterData, state: &mut State) -> &mut Attack;
+    fn apply_skill(&mut self, multiplier: f32, element: &'static ElementalGauge, time: f32, event: &CharacterAction, data: &CharacterData, state: &mut State) -> &mut Attack;
+    fn apply_na(&mut self, multiplier: f32, element: &'static ElementalGauge, time: f32, event: &CharacterAction, data: &CharacterData, state: &mut State) -> &mut Attack;
+    fn apply_ca(&mut self, multiplier: f32, element: &'static ElementalGauge, time: f32, event: &CharacterAction, data: &CharacterData, state: &mut State) -> &mut Attack;
+    fn apply_plunge(&mut self, multiplier: f32, element: &'static ElementalGauge, time: f32, event: &CharacterAction, data: &CharacterData, state: &mut State) -> &mut Attack;
 }
 
 impl AtkQueue for Vec<Attack> {
-    fn add_burst(&mut self, multiplier: f32, element: &'static ElementalGauge, time: f32, event: &CharacterAction, data: &CharacterData, state: &mut State) -> () {
+    fn add_burst(&mut self, multiplier: f32, element: &'static ElementalGauge, time: f32, event: &CharacterAction, data: &CharacterData, state: &mut State) -> &mut Attack {
         self.push(Attack {
             kind: DamageType::Burst,
             multiplier,
@@ -233,10 +283,12 @@ impl AtkQueue for Vec<Attack> {
             aura_application: state.apply_aura(time, event),
             time,
             idx: data.idx,
+            scaling: Scaling::Atk,
         });
+        self.last_mut().unwrap()
     }
 
-    fn add_skill(&mut self, multiplier: f32, element: &'static ElementalGauge, time: f32, event: &CharacterAction, data: &CharacterData, state: &mut State) -> () {
+    fn add_skill(&mut self, multiplier: f32, element: &'static ElementalGauge, time: f32, event: &CharacterAction, data: &CharacterData, state: &mut State) -> &mut Attack {
         self.push(Attack {
             kind: DamageType::Skill,
             multiplier,
@@ -244,10 +296,12 @@ impl AtkQueue for Vec<Attack> {
             aura_application: state.apply_aura(time, event),
             time,
             idx: data.idx,
+            scaling: Scaling::Atk,
         });
+        self.last_mut().unwrap()
     }
 
-    fn add_na(&mut self, multiplier: f32, element: &'static ElementalGauge, time: f32, event: &CharacterAction, data: &CharacterData, state: &mut State) -> () {
+    fn add_na(&mut self, multiplier: f32, element: &'static ElementalGauge, time: f32, event: &CharacterAction, data: &CharacterData, state: &mut State) -> &mut Attack {
         self.push(Attack {
             kind: DamageType::Na,
             multiplier,
@@ -255,10 +309,12 @@ impl AtkQueue for Vec<Attack> {
             aura_application: state.apply_aura(time, event),
             time,
             idx: data.idx,
+            scaling: Scaling::Atk,
         });
+        self.last_mut().unwrap()
     }
 
-    fn add_ca(&mut self, multiplier: f32, element: &'static ElementalGauge, time: f32, event: &CharacterAction, data: &CharacterData, state: &mut State) -> () {
+    fn add_ca(&mut self, multiplier: f32, element: &'static ElementalGauge, time: f32, event: &CharacterAction, data: &CharacterData, state: &mut State) -> &mut Attack {
         self.push(Attack {
             kind: DamageType::Ca,
             multiplier,
@@ -266,10 +322,25 @@ impl AtkQueue for Vec<Attack> {
             aura_application: state.apply_aura(time, event),
             time,
             idx: data.idx,
+            scaling: Scaling::Atk,
         });
+        self.last_mut().unwrap()
     }
 
-    fn apply_burst(&mut self, multiplier: f32, element: &'static ElementalGauge, time: f32, event: &CharacterAction, data: &CharacterData, state: &mut State) -> () {
+    fn add_plunge(&mut self, multiplier: f32, element: &'static ElementalGauge, time: f32, event: &CharacterAction, data: &CharacterData, state: &mut State) -> &mut Attack {
+        self.push(Attack {
+            kind: DamageType::Plunge,
+            multiplier,
+            element,
+            aura_application: state.apply_aura(time, event),
+            time,
+            idx: data.idx,
+            scaling: Scaling::Atk,
+        });
+        self.last_mut().unwrap()
+    }
+
+    fn apply_burst(&mut self, multiplier: f32, element: &'static ElementalGauge, time: f32, event: &CharacterAction, data: &CharacterData, state: &mut State) -> &mut Attack {
         self.push(Attack {
             kind: DamageType::Burst,
             multiplier,
@@ -277,10 +348,12 @@ impl AtkQueue for Vec<Attack> {
             aura_application: true,
             time,
             idx: data.idx,
+            scaling: Scaling::Atk,
         });
+        self.last_mut().unwrap()
     }
 
-    fn apply_skill(&mut self, multiplier: f32, element: &'static ElementalGauge, time: f32, event: &CharacterAction, data: &CharacterData, state: &mut State) -> () {
+    fn apply_skill(&mut self, multiplier: f32, element: &'static ElementalGauge, time: f32, event: &CharacterAction, data: &CharacterData, state: &mut State) -> &mut Attack {
         self.push(Attack {
             kind: DamageType::Skill,
             multiplier,
@@ -288,10 +361,12 @@ impl AtkQueue for Vec<Attack> {
             aura_application: true,
             time,
             idx: data.idx,
+            scaling: Scaling::Atk,
         });
+        self.last_mut().unwrap()
     }
 
-    fn apply_na(&mut self, multiplier: f32, element: &'static ElementalGauge, time: f32, event: &CharacterAction, data: &CharacterData, state: &mut State) -> () {
+    fn apply_na(&mut self, multiplier: f32, element: &'static ElementalGauge, time: f32, event: &CharacterAction, data: &CharacterData, state: &mut State) -> &mut Attack {
         self.push(Attack {
             kind: DamageType::Na,
             multiplier,
@@ -299,10 +374,12 @@ impl AtkQueue for Vec<Attack> {
             aura_application: true,
             time,
             idx: data.idx,
+            scaling: Scaling::Atk,
         });
+        self.last_mut().unwrap()
     }
 
-    fn apply_ca(&mut self, multiplier: f32, element: &'static ElementalGauge, time: f32, event: &CharacterAction, data: &CharacterData, state: &mut State) -> () {
+    fn apply_ca(&mut self, multiplier: f32, element: &'static ElementalGauge, time: f32, event: &CharacterAction, data: &CharacterData, state: &mut State) -> &mut Attack {
         self.push(Attack {
             kind: DamageType::Ca,
             multiplier,
@@ -310,6 +387,48 @@ impl AtkQueue for Vec<Attack> {
             aura_application: true,
             time,
             idx: data.idx,
+            scaling: Scaling::Atk,
+        });
+        self.last_mut().unwrap()
+    }
+
+    fn apply_plunge(&mut self, multiplier: f32, element: &'static ElementalGauge, time: f32, event: &CharacterAction, data: &CharacterData, state: &mut State) -> &mut Attack {
+        self.push(Attack {
+            kind: DamageType::Plunge,
+            multiplier,
+            element,
+            aura_application: true,
+            time,
+            idx: data.idx,
+            scaling: Scaling::Atk,
         });
+        self.last_mut().unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sim2::element::PYRO_GAUGE1A;
+    use crate::sim2::record::{CharacterRecord, WeaponRecord, Artifact};
+
+    #[test]
+    fn scaling() {
+        let mut state = State::default();
+        state.base_atk = 100.;
+        state.base_def = 500.;
+        state.em = 80.;
+        state.flat_dmg = 10.;
+        assert_eq!(Scaling::Atk.value(&state), 100.);
+        assert_eq!(Scaling::Def.value(&state), 500.);
+        assert_eq!(Scaling::Em.value(&state), 80.);
+        assert_eq!(Scaling::Hp.value(&state), state.HP());
+        let mixed = Scaling::Mixed { atk: 1.0, hp: 0.0, def: 0.5, em: 0.0 };
+        assert_eq!(mixed.value(&state), 350.);
+        let mut atk_queue: Vec<Attack> = Vec::new();
+        let (character, weapon, artifact) = (CharacterRecord::default(), WeaponRecord::default(), Artifact::default());
+        let data = CharacterData::new(0, &character, &weapon, &artifact);
+        atk_queue.apply_skill(100., &PYRO_GAUGE1A, 0., &CharacterAction::PressSkill, &data, &mut state).scaling = Scaling::Def;
+        assert_eq!(atk_queue[0].atk(&state), 510.);
     }
 }
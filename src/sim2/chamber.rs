@@ -0,0 +1,234 @@
+use std::error::Error;
+use std::fs;
+
+use crate::sim2::types::Vision;
+use crate::sim2::attack::DamageResultUtil;
+use crate::sim2::party::{Party, PartyError, SimulationConfig};
+use crate::sim2::record::{Artifact, Enemy, Resistance, Wave};
+use crate::sim2::optimize::Stat;
+use crate::sim2::sections::{lines, Line, ParseError};
+
+// A chamber of the Spiral Abyss. Each half is fought by its own party.
+//
+//     # ley line disorders of both halves
+//     leyline = pyro 50
+//
+//     [first]
+//     member = Diluc, Prototype Archaic, Gladiator's Finale
+//     member = Bennett, Prototype Rancour, Noblesse Oblige
+//     # enemies appear one after another in this order
+//     enemy = hp 1500000, level 95, res 10, pyro 50
+//     enemy = hp 800000
+//
+//     [second]
+//     leyline = cr 20
+//     member = ...
+//     enemy = ...
+#[derive(Debug, Default)]
+pub struct Chamber {
+    pub halves: [ChamberHalf; 2],
+}
+
+#[derive(Debug, Default)]
+pub struct ChamberHalf {
+    // character, weapon and artifact names
+    pub party: Vec<String>,
+    pub waves: Vec<Wave>,
+    // stats added to all members
+    pub leyline: Vec<(Stat, f32)>,
+}
+
+#[derive(Debug)]
+pub struct HalfResult {
+    pub damage: f32,
+    // the times when enemies were killed
+    pub kills: Vec<f32>,
+    pub clear_time: Option<f32>,
+    // HP of enemies that were not killed
+    pub hp_left: f32,
+    pub enemies_left: usize,
+}
+
+impl Chamber {
+    pub fn load(path: &str) -> Result<Self, Box<dyn Error>> {
+        Self::parse(&fs::read_to_string(path)?)
+    }
+
+    pub fn parse(text: &str) -> Result<Self, Box<dyn Error>> {
+        let mut chamber = Self::default();
+        let mut leyline: Vec<(Stat, f32)> = Vec::new();
+        // `None` before the first section
+        let mut half: Option<usize> = None;
+        for line in lines(text) {
+            let (n, line) = line?;
+            let (key, value) = match line {
+                Line::Section("first") => { half = Some(0); continue; },
+                Line::Section("second") => { half = Some(1); continue; },
+                Line::Section(name) => return Err(Box::new(ParseError::new(n, &format!("unknown section: [{}]", name)))),
+                Line::Pair(key, value) => (key, value),
+            };
+            match (key, half) {
+                ("leyline", None) => leyline.push(parse_leyline(n, value)?),
+                ("leyline", Some(h)) => chamber.halves[h].leyline.push(parse_leyline(n, value)?),
+                ("member", Some(h)) => {
+                    let names: Vec<String> = value.split(',').map(|x| x.trim().to_string()).collect();
+                    if names.len() != 3 {
+                        return Err(Box::new(ParseError::new(n, "member should be character, weapon and artifact names.")));
+                    }
+                    chamber.halves[h].party.extend(names);
+                },
+                ("enemy", Some(h)) => chamber.halves[h].waves.push(parse_wave(n, value)?),
+                (_, None) => return Err(Box::new(ParseError::new(n, &format!("`{}` should be in [first] or [second].", key)))),
+                _ => return Err(Box::new(ParseError::new(n, &format!("unknown key: {}", key)))),
+            };
+        }
+        for (h, name) in chamber.halves.iter_mut().zip(["first", "second"].iter()) {
+            if h.party.is_empty() || h.waves.is_empty() {
+                return Err(Box::new(ParseError::new(0, &format!("[{}] needs members and enemies.", name))));
+            }
+            h.leyline.extend(leyline.iter().cloned());
+        }
+        Ok(chamber)
+    }
+}
+
+impl ChamberHalf {
+    // Run `party` against the enemies of this half. Ley line disorders are
+    // added to artifacts during the simulation.
+    pub fn run(&self, party: &mut Party, config: &SimulationConfig) -> Result<HalfResult, PartyError> {
+        let artifacts: Vec<Artifact> = party.members.iter().map(|m| m.artifact.0.clone()).collect();
+        for m in party.members.iter_mut() {
+            for (s, v) in self.leyline.iter() {
+                *s.value(&mut m.artifact.0) += v;
+            }
+        }
+        let mut enemy = Enemy::spawn(&self.waves);
+        let damage = party.run(config, &mut enemy);
+        for (m, a) in party.members.iter_mut().zip(artifacts) {
+            m.artifact.0 = a;
+        }
+        Ok(HalfResult {
+            damage: damage?.total_damage(),
+            clear_time: enemy.clear_time(),
+            hp_left: enemy.hp_left(),
+            enemies_left: if enemy.is_cleared() { 0 } else { enemy.waves.len() + 1 },
+            kills: enemy.kills,
+        })
+    }
+}
+
+// e.g. "pyro 50"
+fn parse_leyline(line: usize, value: &str) -> Result<(Stat, f32), Box<dyn Error>> {
+    let mut words = value.split_whitespace();
+    match (words.next().and_then(Stat::parse), words.next().and_then(|x| x.parse().ok()), words.next()) {
+        (Some(s), Some(v), None) => Ok((s, v)),
+        _ => Err(Box::new(ParseError::new(line, "leyline should be a stat and a value, e.g. `pyro 50`."))),
+    }
+}
+
+// e.g. "hp 1500000, level 95, res 10, pyro 50"
+fn parse_wave(line: usize, value: &str) -> Result<Wave, Box<dyn Error>> {
+    let mut hp: Option<f32> = None;
+    let mut level = 90.0;
+    let mut resistance = Resistance::normal();
+    for item in value.split(',') {
+        let mut words = item.split_whitespace();
+        let (key, v) = match (words.next(), words.next().and_then(|x| x.parse::<f32>().ok()), words.next()) {
+            (Some(k), Some(v), None) => (k, v),
+            _ => return Err(Box::new(ParseError::new(line, &format!("expected a key and a value: {}", item.trim())))),
+        };
+        match key {
+            "hp" if v > 0. => hp = Some(v),
+            "level" => level = v,
+            "res" => resistance = Resistance::all(v),
+            _ => match Vision::parse(key) {
+                Some(e) => *resistance.get_mut(&e) = v,
+                None => return Err(Box::new(ParseError::new(line, &format!("unknown key of enemy: {}", key)))),
+            },
+        };
+    }
+    match hp {
+        Some(hp) => Ok(Wave::new(hp).level(level).resistance(resistance)),
+        None => Err(Box::new(ParseError::new(line, "enemy needs hp greater than 0."))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CHAMBER: &str = "
+leyline = pyro 50
+
+[first]
+member = Diluc, Prototype Archaic, Gladiator's Finale
+member = Bennett, Prototype Rancour, Noblesse Oblige
+enemy = hp 20000, level 95, res 10, pyro 50
+enemy = hp 20000
+
+[second]
+leyline = cr 20
+member = Diluc, Prototype Archaic, Gladiator's Finale # solo
+enemy = hp 100000000
+";
+
+    #[test]
+    fn parse() {
+        let chamber = Chamber::parse(CHAMBER).unwrap();
+        let [first, second] = &chamber.halves;
+        assert_eq!(first.party.len(), 6);
+        assert_eq!(first.waves.len(), 2);
+        assert_eq!(first.waves[0].level, 95.);
+        assert_eq!(first.waves[0].resistance.pyro, 50.);
+        assert_eq!(first.waves[0].resistance.cryo, 10.);
+        assert_eq!(first.leyline, vec![(Stat::Pyro, 50.)]);
+        assert_eq!(second.party[2], "Gladiator's Finale");
+        assert_eq!(second.leyline, vec![(Stat::Cr, 20.), (Stat::Pyro, 50.)]);
+        assert!(Chamber::parse("member = Diluc").is_err());
+        assert!(Chamber::parse("[first]\nenemy = hp 0").is_err());
+        assert!(Chamber::parse("[first]\nenemy = hp 1000").is_err());
+    }
+
+    #[test]
+    fn halves() {
+        let chamber = Chamber::parse(CHAMBER).unwrap();
+        let config = SimulationConfig::default();
+        let mut results = Vec::new();
+        for h in chamber.halves.iter() {
+            let mut party = Party::find(&h.party).unwrap();
+            results.push(h.run(&mut party, &config).unwrap());
+        }
+        assert_eq!(results[0].kills.len(), 2);
+        assert_eq!(results[0].clear_time, results[0].kills.last().cloned());
+        assert_eq!(results[0].hp_left, 0.);
+        assert_eq!(results[1].clear_time, None);
+        assert_eq!(results[1].enemies_left, 1);
+        assert!(results[1].hp_left > 0.);
+        // ley lines are removed after the run
+        let mut party = Party::find(&chamber.halves[1].party).unwrap();
+        let cr = party.members[0].artifact.0.cr;
+        chamber.halves[1].run(&mut party, &config).unwrap();
+        assert_eq!(party.members[0].artifact.0.cr, cr);
+    }
+
+    #[test]
+    fn enemy_level() {
+        // the same party deals less damage to a higher level enemy
+        let text = "
+[first]
+member = Diluc, Prototype Archaic, Gladiator's Finale
+enemy = hp 100000000, level 90
+[second]
+member = Diluc, Prototype Archaic, Gladiator's Finale
+enemy = hp 100000000, level 95
+";
+        let chamber = Chamber::parse(text).unwrap();
+        let config = SimulationConfig::default();
+        let mut results = Vec::new();
+        for h in chamber.halves.iter() {
+            let mut party = Party::find(&h.party).unwrap();
+            results.push(h.run(&mut party, &config).unwrap());
+        }
+        assert!(results[0].damage > results[1].damage);
+    }
+}
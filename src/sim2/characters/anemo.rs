@@ -1,7 +1,7 @@
 use crate::sim2::state::State;
 use crate::sim2::timeline::{ActionState, Timeline};
 use crate::sim2::attack::{Attack, CharacterAttack, AtkQueue};
-use crate::sim2::types::{CharacterAction, DamageType, Vision, FieldCharacterIndex, WeaponType, FieldEnergy, Particle, VecFieldEnergy, ToNaAction};
+use crate::sim2::types::{CharacterAction, DamageType, Vision, FieldCharacterIndex, WeaponType, FieldEnergy, Particle, VecFieldEnergy, Recipient, ToNaAction};
 use crate::sim2::element::{ElementalGauge, PHYSICAL_GAUGE, PYRO_GAUGE1A, PYRO_GAUGE2B, HYDRO_GAUGE1A, HYDRO_GAUGE2B, ELECTRO_GAUGE1A, ELECTRO_GAUGE2B, ELECTRO_GAUGE4C, CRYO_GAUGE1A, CRYO_GAUGE2B, ANEMO_GAUGE1A, ANEMO_GAUGE2B, GEO_GAUGE1A, GEO_GAUGE2B, DENDRO_GAUGE1A, DENDRO_GAUGE2B};
 use crate::sim2::record::{CharacterRecord, CharacterData, Enemy};
 
@@ -279,7 +279,7 @@ impl Timeline for Jean {
     fn accelerate(&mut self, field_energy: &mut Vec<FieldEnergy>, event: &CharacterAction, state: &mut ActionState, data: &CharacterData) -> () {
         match event {
             // a4
-            CharacterAction::Burst => state.energy += 16.,
+            CharacterAction::Burst => field_energy.push_e_to(16., Recipient::Oneself),
             CharacterAction::PressSkill => field_energy.push_p(Particle::new(data.character.vision, 3.)),
             _ => (),
         }
@@ -368,7 +368,8 @@ impl Timeline for Venti {
     // generate energy and modify acceleration states according to the event
     fn accelerate(&mut self, field_energy: &mut Vec<FieldEnergy>, event: &CharacterAction, state: &mut ActionState, data: &CharacterData) -> () {
         match event {
-            CharacterAction::Burst => field_energy.push_e(15.),
+            // A4: Stormeye restores energy to Venti and the members of the absorbed element
+            CharacterAction::Burst => field_energy.push_e_to(15., Recipient::Absorbed),
             CharacterAction::PressSkill => field_energy.push_p(Particle::new(data.character.vision, 3.)),
             _ => (),
         }
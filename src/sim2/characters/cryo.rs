@@ -1,9 +1,9 @@
 use crate::sim2::state::State;
 use crate::sim2::timeline::{ActionState, Timeline};
-use crate::sim2::attack::{Attack, CharacterAttack, AtkQueue};
+use crate::sim2::attack::{Attack, Scaling, CharacterAttack, AtkQueue};
 use crate::sim2::types::{CharacterAction, DamageType, Vision, FieldCharacterIndex, WeaponType, FieldEnergy, Particle, VecFieldEnergy, ToNaAction};
 use crate::sim2::element::{ElementalGauge, PHYSICAL_GAUGE, PYRO_GAUGE1A, PYRO_GAUGE2B, HYDRO_GAUGE1A, HYDRO_GAUGE2B, ELECTRO_GAUGE1A, ELECTRO_GAUGE2B, ELECTRO_GAUGE4C, CRYO_GAUGE1A, CRYO_GAUGE2B, ANEMO_GAUGE1A, ANEMO_GAUGE2B, GEO_GAUGE1A, GEO_GAUGE2B, DENDRO_GAUGE1A, DENDRO_GAUGE2B};
-use crate::sim2::record::{CharacterRecord, CharacterData, Enemy};
+use crate::sim2::record::{CharacterRecord, CharacterData, Enemy, Debuff, DebuffStat};
 
 use WeaponType::*;
 use Vision::*;
@@ -20,9 +20,7 @@ use Vision::*;
 // by this blade will have their Cryo RES decreased by 10% for 8s.
 #[derive(Debug)]
 pub struct Chongyun {
-    apply_debuff: bool,
     skill_time: f32,
-    a4_time: f32,
 }
 
 impl Chongyun {
@@ -36,9 +34,7 @@ impl Chongyun {
 
     pub fn new() -> Self {
         Self {
-            apply_debuff: false,
             skill_time: -99.,
-            a4_time: -99.,
         }
     }
 }
@@ -81,6 +77,7 @@ impl CharacterAttack for Chongyun {
                 aura_application: true,
                 time: time + 0.3333 * i as f32,
                 idx: data.idx,
+                scaling: Scaling::Atk,
             });
         }
     }
@@ -109,25 +106,17 @@ impl CharacterAttack for Chongyun {
     fn modify(&mut self, action_state: &ActionState, data: &CharacterData, attack: &mut Attack, state: &mut State, enemy: &mut Enemy) -> () {
         if action_state.did_skill() {
             self.skill_time = action_state.current_time;
-            self.a4_time = self.skill_time+10.;
+            // the blade is summoned when the field disappears
+            enemy.apply_debuff(action_state.current_time, Debuff::new("Chongyun", DebuffStat::Res(Cryo), 10., self.skill_time + 10., 8.));
         }
         // skill infusion, do not infuse if the attack is infused already
         if attack.time - self.skill_time <= 3. && attack.element.aura != Physical {
             attack.element = &CRYO_GAUGE1A;
         }
-        if !self.apply_debuff && attack.time - self.a4_time <= 10. {
-            self.apply_debuff = true;
-            enemy.debuff.cryo += 10.;
-        } else if self.apply_debuff && attack.time - self.a4_time > 10. {
-            self.apply_debuff = false;
-            enemy.debuff.cryo -= 10.;
-        }
     }
 
     fn reset_modify(&mut self) -> () {
-        self.apply_debuff = false;
         self.skill_time = -99.;
-        self.a4_time = -99.;
     }
 }
 
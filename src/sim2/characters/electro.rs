@@ -3,7 +3,7 @@ use crate::sim2::timeline::{ActionState, Timeline};
 use crate::sim2::attack::{Attack, CharacterAttack, AtkQueue};
 use crate::sim2::types::{CharacterAction, DamageType, Vision, FieldCharacterIndex, WeaponType, FieldEnergy, Particle, VecFieldEnergy, ToNaAction};
 use crate::sim2::element::{ElementalGauge, PHYSICAL_GAUGE, PYRO_GAUGE1A, PYRO_GAUGE2B, HYDRO_GAUGE1A, HYDRO_GAUGE2B, ELECTRO_GAUGE1A, ELECTRO_GAUGE2B, ELECTRO_GAUGE4C, CRYO_GAUGE1A, CRYO_GAUGE2B, ANEMO_GAUGE1A, ANEMO_GAUGE2B, GEO_GAUGE1A, GEO_GAUGE2B, DENDRO_GAUGE1A, DENDRO_GAUGE2B};
-use crate::sim2::record::{CharacterRecord, CharacterData, Enemy};
+use crate::sim2::record::{CharacterRecord, CharacterData, Enemy, Debuff, DebuffStat};
 
 use WeaponType::*;
 use Vision::*;
@@ -221,7 +221,6 @@ impl CharacterAttack for Fischl {
 pub struct Lisa {
     burst_time: f32,
     conductive_status: u8,
-    apply_debuff: bool,
 }
 
 impl Lisa {
@@ -237,7 +236,6 @@ impl Lisa {
         Self {
             burst_time: -99.,
             conductive_status: 0,
-            apply_debuff: false,
         }
     }
 }
@@ -317,20 +315,14 @@ impl CharacterAttack for Lisa {
     fn modify(&mut self, action_state: &ActionState, data: &CharacterData, attack: &mut Attack, state: &mut State, enemy: &mut Enemy) -> () {
         if action_state.did_burst() {
             self.burst_time = action_state.current_time;
-        }
-        if !self.apply_debuff && attack.time - self.burst_time <= 25. {
-            self.apply_debuff = true;
-            enemy.def_down += 15.;
-        } else if self.apply_debuff && attack.time - self.burst_time > 25. {
-            self.apply_debuff = false;
-            enemy.def_down -= 15.;
+            // 15s of Lightning Rose + 10s of A4
+            enemy.apply_debuff(action_state.current_time, Debuff::new("Lisa", DebuffStat::Def, 15., self.burst_time, 25.));
         }
     }
 
     fn reset_modify(&mut self) -> () {
         self.burst_time = -99.;
         self.conductive_status = 0;
-        self.apply_debuff = false;
     }
 }
 
@@ -515,7 +507,8 @@ impl Timeline for Keqing {
     fn accelerate(&mut self, field_energy: &mut Vec<FieldEnergy>, event: &CharacterAction, state: &mut ActionState, data: &CharacterData) -> () {
         match event {
             CharacterAction::Burst => self.burst_time = state.current_time,
-            CharacterAction::PressSkill => field_energy.push_p(Particle::new(data.character.vision, 2.5)),
+            // 2 or 3 particles
+            CharacterAction::PressSkill => field_energy.push_p(Particle::chance(data.character.vision, &[(2., 0.5), (3., 0.5)])),
             _ => (),
         };
         if state.current_time - self.burst_time <= 8. {
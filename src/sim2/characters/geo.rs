@@ -1,6 +1,6 @@
 use crate::sim2::state::State;
 use crate::sim2::timeline::{ActionState, Timeline};
-use crate::sim2::attack::{Attack, CharacterAttack, AtkQueue};
+use crate::sim2::attack::{Attack, Scaling, CharacterAttack, AtkQueue};
 use crate::sim2::types::{CharacterAction, DamageType, Vision, FieldCharacterIndex, WeaponType, FieldEnergy, Particle, VecFieldEnergy, ToNaAction};
 use crate::sim2::element::{ElementalGauge, PHYSICAL_GAUGE, PYRO_GAUGE1A, PYRO_GAUGE2B, HYDRO_GAUGE1A, HYDRO_GAUGE2B, ELECTRO_GAUGE1A, ELECTRO_GAUGE2B, ELECTRO_GAUGE4C, CRYO_GAUGE1A, CRYO_GAUGE2B, ANEMO_GAUGE1A, ANEMO_GAUGE2B, GEO_GAUGE1A, GEO_GAUGE2B, DENDRO_GAUGE1A, DENDRO_GAUGE2B};
 use crate::sim2::record::{CharacterRecord, CharacterData, Enemy};
@@ -47,7 +47,7 @@ impl Timeline for Ningguang {
         // is burst CD off and has enough energy
         } else if state.rel_time.burst >= 12. && state.energy >= 40. {
             CharacterAction::Burst
-        } else if data.idx.is_on_field() && self.star_jade > 1 && state.rel_time.na >= 0.8 {
+        } else if data.idx.is_on_field() && self.star_jade > 1 && state.rel_time.na >= 0.8 && state.can_ca() {
             CharacterAction::Ca(state.ca_carryover(1.5))
         // check if normal attacks can be used (both animations are ended)
         } else if state.rel_time.na >= 0.8 && state.rel_time.ca >= 1.5 {
@@ -191,7 +191,7 @@ impl CharacterAttack for Noelle {
     }
 
     fn press(&mut self, time: f32, event: &CharacterAction, data: &CharacterData, atk_queue: &mut Vec<Attack>, state: &mut State, enemy: &mut Enemy) -> () {
-        atk_queue.add_skill(216.0, &GEO_GAUGE1A, time, event, data, state);
+        atk_queue.add_skill(216.0, &GEO_GAUGE1A, time, event, data, state).scaling = Scaling::Def;
     }
 
     fn na1(&mut self, time: f32, event: &CharacterAction, data: &CharacterData, atk_queue: &mut Vec<Attack>, state: &mut State, enemy: &mut Enemy) -> () {
@@ -45,7 +45,7 @@ impl Timeline for Amber {
         } else if state.rel_time.burst >= 12. && state.energy >= 40. {
             CharacterAction::Burst
         // check if normal attacks can be used (both animations are ended)
-        } else if data.idx.is_on_field() && state.rel_time.ca >= 2. {
+        } else if data.idx.is_on_field() && state.rel_time.ca >= 2. && state.can_ca() {
             CharacterAction::Ca(state.ca_carryover(2.))
         } else {
             CharacterAction::StandStill
@@ -397,9 +397,9 @@ impl CharacterAttack for Diluc {
             self.charge = 1;
         }
         match charge {
-            1 => atk_queue.add_skill(169.92, &PYRO_GAUGE1A, time, event, data, state),
-            2 => atk_queue.add_skill(175.68, &PYRO_GAUGE1A, time, event, data, state),
-            3 => atk_queue.add_skill(231.84, &PYRO_GAUGE1A, time, event, data, state),
+            1 => { atk_queue.add_skill(169.92, &PYRO_GAUGE1A, time, event, data, state); },
+            2 => { atk_queue.add_skill(175.68, &PYRO_GAUGE1A, time, event, data, state); },
+            3 => { atk_queue.add_skill(231.84, &PYRO_GAUGE1A, time, event, data, state); },
             _ => (),
         }
     }
@@ -496,6 +496,10 @@ impl Timeline for Klee {
             CharacterAction::Ca(_) => field_energy.push_e(2.),
             _ => (),
         }
+        // the next charged attack consumes Explosive Spark instead of stamina
+        if self.explosive_spark {
+            state.reduce_stamina = 100.;
+        }
     }
 
     fn reset_timeline(&mut self) -> () {
@@ -555,3 +559,30 @@ impl CharacterAttack for Klee {
         self.explosive_spark = false;
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sim2::record::{WeaponRecord, Artifact};
+
+    #[test]
+    fn klee_explosive_spark_without_stamina() {
+        let mut klee = Klee::new();
+        klee.explosive_spark = true;
+        let cr = Klee::record();
+        let wr = WeaponRecord::default();
+        let ar = Artifact::default();
+        let mut data = CharacterData::new(0, &cr, &wr, &ar);
+        let mut state = ActionState::new();
+        state.init(&data);
+        state.rel_time.press = 0.;
+        state.stamina = 0.;
+        let mut field_energy: Vec<FieldEnergy> = Vec::new();
+        klee.accelerate(&mut field_energy, &CharacterAction::StandStill, &mut state, &data);
+        // the charged attack of Explosive Spark costs no stamina
+        let action = klee.decide_action(&state, &mut data);
+        assert!(action.is_ca());
+        assert!(state.has_stamina(&action));
+        assert!(!klee.explosive_spark);
+    }
+}
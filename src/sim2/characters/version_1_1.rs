@@ -57,7 +57,7 @@ impl Timeline for Tartaglia {
         } else if state.rel_time.na >= 0.4025 {
             // 6 attacks in 2.415 seconds
             data.na_idx.to_na(6, state.na_carryover(0.4025))
-        } else if data.idx.is_on_field() && state.rel_time.ca >= 10. {
+        } else if data.idx.is_on_field() && state.rel_time.ca >= 10. && state.can_ca() {
             CharacterAction::Ca(state.ca_carryover(10.))
         } else {
             CharacterAction::StandStill
@@ -1,6 +1,6 @@
 use crate::sim2::state::State;
 use crate::sim2::timeline::{ActionState, Timeline};
-use crate::sim2::attack::{Attack, CharacterAttack, AtkQueue};
+use crate::sim2::attack::{Attack, Scaling, CharacterAttack, AtkQueue};
 use crate::sim2::types::{CharacterAction, DamageType, Vision, FieldCharacterIndex, WeaponType, FieldEnergy, Particle, VecFieldEnergy, ToNaAction};
 use crate::sim2::element::{ElementalGauge, PHYSICAL_GAUGE, PYRO_GAUGE1A, PYRO_GAUGE2B, HYDRO_GAUGE1A, HYDRO_GAUGE2B, ELECTRO_GAUGE1A, ELECTRO_GAUGE2B, CRYO_GAUGE1A, CRYO_GAUGE2B, ANEMO_GAUGE1A, ANEMO_GAUGE2B, GEO_GAUGE1A, GEO_GAUGE2B, DENDRO_GAUGE1A, DENDRO_GAUGE2B};
 use crate::sim2::record::{CharacterRecord, CharacterData, Enemy};
@@ -72,9 +72,9 @@ impl CharacterAttack for Albedo {
     }
 
     fn press(&mut self, time: f32, event: &CharacterAction, data: &CharacterData, atk_queue: &mut Vec<Attack>, state: &mut State, enemy: &mut Enemy) -> () {
-        atk_queue.add_skill(234.72, &GEO_GAUGE1A, time, event, data, state);
+        atk_queue.add_skill(234.72, &GEO_GAUGE1A, time, event, data, state).scaling = Scaling::Def;
         for i in 0..10 {
-            atk_queue.add_skill(240.48, &GEO_GAUGE1A, time + (2 * i) as f32, event, data, state);
+            atk_queue.add_skill(240.48, &GEO_GAUGE1A, time + (2 * i) as f32, event, data, state).scaling = Scaling::Def;
         }
     }
 
@@ -105,6 +105,10 @@ impl CharacterAttack for Albedo {
         if attack.time - self.burst_time <= 10. {
             state.em += 125.;
         }
+        // Transient Blossoms
+        if attack.idx == data.idx && attack.kind == DamageType::Skill && attack.multiplier == 240.48 && enemy.hp_ratio() < 0.5 {
+            state.skill_dmg += 25.;
+        }
     }
 
     fn reset_modify(&mut self) -> () {
@@ -147,7 +151,7 @@ impl Timeline for Ganyu {
         } else if state.rel_time.burst >= 15. && state.energy >= 60. {
             CharacterAction::Burst
         // check if normal attacks can be used (both animations are ended)
-        } else if data.idx.is_on_field() && state.rel_time.ca >= 2.266 {
+        } else if data.idx.is_on_field() && state.rel_time.ca >= 2.266 && state.can_ca() {
             CharacterAction::Ca(state.ca_carryover(2.266))
         } else {
             CharacterAction::StandStill
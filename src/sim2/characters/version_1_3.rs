@@ -65,9 +65,9 @@ impl Timeline for Xiao {
         } else if state.rel_time.burst >= 18. && state.energy >= 70. {
             self.burst_time = state.current_time;
             CharacterAction::Burst
-        // check if normal attacks can be used (both animations are ended)
-        } else if data.idx.is_on_field() && during_burst && state.rel_time.ca >= 1.5 {
-            CharacterAction::Ca(state.ca_carryover(1.5))
+        // high plunge loop during the burst
+        } else if data.idx.is_on_field() && during_burst && state.rel_time.plunge >= 1.5 {
+            CharacterAction::Plunge
         } else if !during_burst && state.rel_time.na >= 0.625 {
             // 6 attacks in 3.75 seconds
             data.na_idx.to_na(6, state.na_carryover(0.625))
@@ -126,8 +126,8 @@ impl CharacterAttack for Xiao {
         atk_queue.add_na(170.97, self.infusion(time, data.idx.is_on_field()), time, event, data, state);
     }
 
-    fn ca(&mut self, time: f32, event: &CharacterAction, data: &CharacterData, atk_queue: &mut Vec<Attack>, state: &mut State, enemy: &mut Enemy) -> () {
-        atk_queue.add_ca(404.02, self.infusion(time, data.idx.is_on_field()), time, event, data, state);
+    fn plunge(&mut self, time: f32, event: &CharacterAction, data: &CharacterData, atk_queue: &mut Vec<Attack>, state: &mut State, enemy: &mut Enemy) -> () {
+        atk_queue.add_plunge(404.02, self.infusion(time, data.idx.is_on_field()), time, event, data, state);
     }
 
     fn reset_attack(&mut self) -> () {
@@ -148,6 +148,7 @@ impl CharacterAttack for Xiao {
             if burst_duration <= 18. {
                 state.na_dmg += 95.2;
                 state.ca_dmg += 95.2;
+                state.plunge_dmg += 95.2;
             }
             state.all_dmg += if burst_duration < 3. {
                 5.
@@ -224,7 +225,7 @@ impl Timeline for HuTao {
         } else if state.rel_time.press >= 16. {
             CharacterAction::PressSkill
         // check if normal attacks can be used (both animations are ended)
-        } else if data.idx.is_on_field() && during_skill && state.rel_time.ca >= 0.925 {
+        } else if data.idx.is_on_field() && during_skill && state.rel_time.ca >= 0.925 && state.can_ca() {
             CharacterAction::Ca(state.ca_carryover(0.925))
         } else if !during_skill && state.rel_time.na >= 0.4875 {
             // 6 attacks in 2.925 seconds
@@ -3,7 +3,7 @@ use crate::sim2::timeline::{ActionState, Timeline};
 use crate::sim2::attack::{Attack, CharacterAttack, AtkQueue};
 use crate::sim2::types::{PeriodicStack, CharacterAction, DamageType, Vision, FieldCharacterIndex, WeaponType, FieldEnergy, Particle, VecFieldEnergy, ToNaAction};
 use crate::sim2::element::{ElementalGauge, PHYSICAL_GAUGE, PYRO_GAUGE1A, PYRO_GAUGE2B, HYDRO_GAUGE1A, HYDRO_GAUGE2B, ELECTRO_GAUGE1A, ELECTRO_GAUGE2B, CRYO_GAUGE1A, CRYO_GAUGE2B, ANEMO_GAUGE1A, ANEMO_GAUGE2B, GEO_GAUGE1A, GEO_GAUGE2B, DENDRO_GAUGE1A, DENDRO_GAUGE2B};
-use crate::sim2::record::{CharacterRecord, CharacterData, Enemy};
+use crate::sim2::record::{CharacterRecord, CharacterData, Enemy, Debuff, DebuffStat};
 
 use WeaponType::*;
 use Vision::*;
@@ -49,7 +49,7 @@ impl Timeline for Yanfei {
     // perform an action
     fn decide_action(&mut self, state: &ActionState, data: &mut CharacterData) -> CharacterAction {
         // use ca
-        if data.idx.is_on_field() && self.scarlet_seal >= 3 && state.rel_time.ca >= 1. && state.rel_time.na >= 0.5 {
+        if data.idx.is_on_field() && self.scarlet_seal >= 3 && state.rel_time.ca >= 1. && state.rel_time.na >= 0.5 && state.can_ca() {
             CharacterAction::Ca(state.ca_carryover(1.))
         // check if skill can be used
         } else if state.rel_time.press >= 9. {
@@ -170,8 +170,6 @@ pub struct Eula {
     grimheart: u8,
     burst_time: f32,
     lightfall_sword_stack: f32,
-    apply_debuff: bool,
-    debuff_time: f32,
 }
 
 impl Eula {
@@ -188,8 +186,6 @@ impl Eula {
             grimheart: 0,
             burst_time: -99.,
             lightfall_sword_stack: 0.,
-            apply_debuff: false,
-            debuff_time: -99.,
         }
     }
 }
@@ -308,24 +304,15 @@ impl CharacterAttack for Eula {
             if data.idx.0 == 0 && attack.kind == DamageType::Burst && attack.multiplier == 725.56 {
                 attack.multiplier += 148.24 * self.lightfall_sword_stack;
             }
-            if !self.apply_debuff && action_state.did_hold() {
-                self.apply_debuff = true;
-                self.debuff_time = action_state.current_time;
-                enemy.debuff.cryo += 25.;
-                enemy.debuff.physical += 25.;
+            if action_state.did_hold() {
+                enemy.apply_debuff(action_state.current_time, Debuff::new("Eula", DebuffStat::Res(Cryo), 25., action_state.current_time, 7.5));
+                enemy.apply_debuff(action_state.current_time, Debuff::new("Eula", DebuffStat::Res(Physical), 25., action_state.current_time, 7.5));
             }
         }
-        if self.apply_debuff && attack.time - self.debuff_time > 7.5 {
-            self.apply_debuff = false;
-            enemy.debuff.cryo -= 25.;
-            enemy.debuff.physical -= 25.;
-        }
     }
 
     fn reset_modify(&mut self) -> () {
         self.grimheart = 0;
         self.burst_time = -99.;
-        self.apply_debuff = false;
-        self.debuff_time = -99.;
     }
 }
@@ -46,7 +46,7 @@ impl Kazuha {
 impl Timeline for Kazuha {
     // perform an action
     fn decide_action(&mut self, state: &ActionState, data: &mut CharacterData) -> CharacterAction {
-        if self.midare_ranzan {
+        if self.midare_ranzan && state.can_ca() {
             CharacterAction::Ca(0.)
         // check if skill can be used
         } else if state.rel_time.hold >= 9. {
@@ -1,7 +1,7 @@
 use crate::sim2::state::State;
-use crate::sim2::timeline::{ActionState, Timeline};
+use crate::sim2::timeline::{ActionState, Timeline, MAX_STAMINA};
 use crate::sim2::attack::{Attack, CharacterAttack, AtkQueue};
-use crate::sim2::types::{CharacterAction, DamageType, Vision, FieldCharacterIndex, WeaponType, FieldEnergy, Particle, VecFieldEnergy, ToNaAction};
+use crate::sim2::types::{CharacterAction, DamageType, Vision, FieldCharacterIndex, WeaponType, FieldEnergy, Particle, VecFieldEnergy, Recipient, ToNaAction};
 use crate::sim2::element::{ElementalGauge, PHYSICAL_GAUGE, PYRO_GAUGE1A, PYRO_GAUGE2B, HYDRO_GAUGE1A, HYDRO_GAUGE2B, ELECTRO_GAUGE1A, ELECTRO_GAUGE2B, CRYO_GAUGE1A, CRYO_GAUGE2B, ANEMO_GAUGE1A, ANEMO_GAUGE2B, GEO_GAUGE1A, GEO_GAUGE2B, DENDRO_GAUGE1A, DENDRO_GAUGE2B};
 use crate::sim2::record::{CharacterRecord, CharacterData, Enemy};
 
@@ -17,6 +17,8 @@ use Vision::*;
 // - Gains 18% Cryo DMG Bonus for 10s.
 #[derive(Debug)]
 pub struct Ayaka {
+    // Kamisato Art: Senho cancels the end of the NA combo
+    dash: bool,
     skill_time: f32,
 }
 
@@ -31,6 +33,7 @@ impl Ayaka {
 
     pub fn new() -> Self {
         Self {
+            dash: false,
             skill_time: -99.
         }
     }
@@ -45,8 +48,15 @@ impl Timeline for Ayaka {
         // is burst CD off and has enough energy
         } else if state.rel_time.burst >= 20. && state.energy >= 80. {
             CharacterAction::Burst
+        // dash after the combo
+        } else if data.idx.is_on_field() && self.dash && state.rel_time.na >= 0.4234 && state.has_stamina(&CharacterAction::Dash) {
+            self.dash = false;
+            CharacterAction::Dash
         // check if normal attacks can be used (both animations are ended)
         } else if state.rel_time.na >= 0.4234 {
+            if data.na_idx == 5 {
+                self.dash = true;
+            }
             // 5 attacks in 2.117 seconds
             data.na_idx.to_na(5, state.na_carryover(0.4234))
         } else {
@@ -57,10 +67,17 @@ impl Timeline for Ayaka {
     // generate energy and modify acceleration states according to the event
     fn accelerate(&mut self, field_energy: &mut Vec<FieldEnergy>, event: &CharacterAction, state: &mut ActionState, data: &CharacterData) -> () {
         match event {
-            CharacterAction::PressSkill => field_energy.push_p(Particle::new(data.character.vision, 4.5)),
+            // 4 or 5 particles
+            CharacterAction::PressSkill => field_energy.push_p(Particle::chance(data.character.vision, &[(4., 0.5), (5., 0.5)])),
+            // a4 restores 10 stamina at the end of Kamisato Art: Senho
+            CharacterAction::Dash => state.stamina = (state.stamina + 10.).min(MAX_STAMINA),
             _ => (),
         }
     }
+
+    fn reset_timeline(&mut self) -> () {
+        self.dash = false;
+    }
 }
 
 impl CharacterAttack for Ayaka {
@@ -402,11 +419,11 @@ impl Timeline for TravelerElectro {
                 field_energy.push_p(Particle::new(data.character.vision, 3.5));
                 // Abundance Amulets
                 let bonus = 1. + 0.1 * state.er / 100.;
-                field_energy.push_e(8. * bonus);
+                field_energy.push_e_to(8. * bonus, Recipient::OnField);
             },
             CharacterAction::Burst => {
                 // the thunder hits 20 times
-                field_energy.push_e(20.);
+                field_energy.push_e_to(20., Recipient::OnField);
             },
             _ => (),
         }
@@ -1,13 +1,18 @@
 use crate::sim2::state::State;
 use crate::sim2::timeline::{ActionState, Timeline};
-use crate::sim2::attack::{Attack, CharacterAttack, AtkQueue};
-use crate::sim2::types::{CharacterAction, DamageType, Vision, FieldCharacterIndex, WeaponType, FieldEnergy, Particle, VecFieldEnergy, ToNaAction};
+use crate::sim2::attack::{Attack, Scaling, CharacterAttack, AtkQueue};
+use crate::sim2::types::{CharacterAction, DamageType, Vision, FieldCharacterIndex, WeaponType, FieldEnergy, Particle, VecFieldEnergy, Recipient, ToNaAction};
 use crate::sim2::element::{ElementalGauge, PHYSICAL_GAUGE, PYRO_GAUGE1A, PYRO_GAUGE2B, HYDRO_GAUGE1A, HYDRO_GAUGE2B, ELECTRO_GAUGE1A, ELECTRO_GAUGE2B, CRYO_GAUGE1A, CRYO_GAUGE2B, ANEMO_GAUGE1A, ANEMO_GAUGE2B, GEO_GAUGE1A, GEO_GAUGE2B, DENDRO_GAUGE1A, DENDRO_GAUGE2B};
 use crate::sim2::record::{CharacterRecord, CharacterData, Enemy};
+use crate::sim2::event::Event;
 
 use WeaponType::*;
 use Vision::*;
 
+// While Eye of Stormy Judgment is active, Chakra Desiderata gains Resolve
+// stacks based on the Energy spent by party members on their bursts. The
+// stacks are consumed by her burst, up to 60.
+
 // When nearby party members gain Elemental Orbs or Particles, Chakra Desiderata
 // gains 2 Resolve stacks. This effect can occur once every 3s.
 
@@ -18,6 +23,9 @@ use Vision::*;
 #[derive(Debug)]
 pub struct RaidenShogun {
     resolve_stack: f32,
+    // stacks consumed by the last burst
+    resolve: f32,
+    particle_time: f32,
     burst_time: f32,
     energy_restoration: u8,
 }
@@ -33,7 +41,9 @@ impl RaidenShogun {
 
     pub fn new() -> Self {
         Self {
-            resolve_stack: 40.0, // TODO starting 200 energy consumption
+            resolve_stack: 0.,
+            resolve: 0.,
+            particle_time: -99.,
             burst_time: -99.,
             energy_restoration: 0,
         }
@@ -41,7 +51,7 @@ impl RaidenShogun {
 
     fn musou_isshin_na(&mut self, multiplier: f32, time: f32, event: &CharacterAction, data: &CharacterData, atk_queue: &mut Vec<Attack>, state: &mut State) -> () {
         if time - self.burst_time <= 7. {
-            atk_queue.add_burst(multiplier + 1.31 * self.resolve_stack, &ELECTRO_GAUGE1A, time, event, data, state);
+            atk_queue.add_burst(multiplier + 1.31 * self.resolve, &ELECTRO_GAUGE1A, time, event, data, state);
         } else {
             atk_queue.add_na(multiplier, &PHYSICAL_GAUGE, time, event, data, state);
         }
@@ -82,7 +92,8 @@ impl Timeline for RaidenShogun {
             CharacterAction::Na6(_) => {
                 if state.current_time - self.burst_time <= 7. && self.energy_restoration < 5 {
                     let bonus = 1.0 + 0.6 * state.er / 100.0;
-                    field_energy.push_e(2.5 * bonus);
+                    // the Raiden Shogun does not restore her own energy
+                    field_energy.push_e_to(2.5 * bonus, Recipient::Others);
                     self.energy_restoration += 1;
                 }
             },
@@ -99,7 +110,9 @@ impl Timeline for RaidenShogun {
 impl CharacterAttack for RaidenShogun {
     fn burst(&mut self, time: f32, event: &CharacterAction, data: &CharacterData, atk_queue: &mut Vec<Attack>, state: &mut State, enemy: &mut Enemy) -> () {
         self.burst_time = time;
-        atk_queue.add_burst(721.44 + 7. * self.resolve_stack, &ELECTRO_GAUGE2B, time, event, data, state);
+        self.resolve = self.resolve_stack;
+        self.resolve_stack = 0.;
+        atk_queue.add_burst(721.44 + 7. * self.resolve, &ELECTRO_GAUGE2B, time, event, data, state);
     }
 
     fn press(&mut self, time: f32, event: &CharacterAction, data: &CharacterData, atk_queue: &mut Vec<Attack>, state: &mut State, enemy: &mut Enemy) -> () {
@@ -131,9 +144,26 @@ impl CharacterAttack for RaidenShogun {
     }
 
     fn reset_attack(&mut self) -> () {
+        self.resolve_stack = 0.;
+        self.resolve = 0.;
+        self.particle_time = -99.;
         self.burst_time = -99.;
     }
 
+    fn event(&mut self, time: f32, event: &Event, data: &CharacterData) -> () {
+        match event {
+            Event::Burst { idx, energy } if *idx != data.idx => self.resolve_stack += 0.2 * energy,
+            Event::Particle { .. } if time - self.particle_time >= 3. => {
+                self.particle_time = time;
+                self.resolve_stack += 2.;
+            },
+            _ => (),
+        }
+        if self.resolve_stack > 60. {
+            self.resolve_stack = 60.;
+        }
+    }
+
     fn modify(&mut self, action_state: &ActionState, data: &CharacterData, attack: &mut Attack, state: &mut State, enemy: &mut Enemy) -> () {
         // if action_state.did_burst() {
         //     self.burst_time = action_state.current_time;
@@ -446,41 +476,42 @@ impl Timeline for SangonomiyaKokomi {
     }
 }
 
+impl SangonomiyaKokomi {
+    // Nereid's Ascension adds `bonus`% of Max HP to the DMG of NA and skill
+    // hits that land during the burst. ripples queued before the burst are
+    // not boosted.
+    fn scaling(&self, time: f32, multiplier: f32, bonus: f32) -> Scaling {
+        if time - self.burst_time <= 10. {
+            Scaling::Mixed { atk: 1., hp: bonus / multiplier, def: 0., em: 0. }
+        } else {
+            Scaling::Atk
+        }
+    }
+}
+
 impl CharacterAttack for SangonomiyaKokomi {
     fn burst(&mut self, time: f32, event: &CharacterAction, data: &CharacterData, atk_queue: &mut Vec<Attack>, state: &mut State, enemy: &mut Enemy) -> () {
-        atk_queue.add_burst(100., &HYDRO_GAUGE2B, time, event, data, state);
+        self.burst_time = time;
+        atk_queue.add_burst(18.75, &HYDRO_GAUGE2B, time, event, data, state).scaling = Scaling::Hp;
     }
 
     fn press(&mut self, time: f32, event: &CharacterAction, data: &CharacterData, atk_queue: &mut Vec<Attack>, state: &mut State, enemy: &mut Enemy) -> () {
         for i in 0..7 {
-            atk_queue.apply_skill(196.54, &HYDRO_GAUGE1A, time + (2 * i) as f32, event, data, state);
+            let t = time + (2 * i) as f32;
+            atk_queue.apply_skill(196.54, &HYDRO_GAUGE1A, t, event, data, state).scaling = self.scaling(t, 196.54, 12.77);
         }
     }
 
     fn na1(&mut self, time: f32, event: &CharacterAction, data: &CharacterData, atk_queue: &mut Vec<Attack>, state: &mut State, enemy: &mut Enemy) -> () {
-        atk_queue.add_na(123.08, &HYDRO_GAUGE1A, time, event, data, state);
+        atk_queue.add_na(123.08, &HYDRO_GAUGE1A, time, event, data, state).scaling = self.scaling(time, 123.08, 8.71);
     }
 
     fn na2(&mut self, time: f32, event: &CharacterAction, data: &CharacterData, atk_queue: &mut Vec<Attack>, state: &mut State, enemy: &mut Enemy) -> () {
-        atk_queue.add_na(110.77, &HYDRO_GAUGE1A, time, event, data, state);
+        atk_queue.add_na(110.77, &HYDRO_GAUGE1A, time, event, data, state).scaling = self.scaling(time, 110.77, 8.71);
     }
 
     fn na3(&mut self, time: f32, event: &CharacterAction, data: &CharacterData, atk_queue: &mut Vec<Attack>, state: &mut State, enemy: &mut Enemy) -> () {
-        atk_queue.add_na(169.75, &HYDRO_GAUGE1A, time, event, data, state);
-    }
-
-    fn modify(&mut self, action_state: &ActionState, data: &CharacterData, attack: &mut Attack, state: &mut State, enemy: &mut Enemy) -> () {
-        if action_state.did_burst() {
-            self.burst_time = action_state.current_time;
-        }
-        if attack.idx == data.idx && attack.time - self.burst_time <= 10. {
-            match &attack.kind {
-                DamageType::Burst => state.flat_dmg += 18.75 / 100. * state.HP(),
-                DamageType::Skill => state.flat_dmg += 12.77 / 100. * state.HP(),
-                DamageType::Na    => state.flat_dmg += 8.71 / 100. * state.HP(),
-                _ => (),
-            }
-        }
+        atk_queue.add_na(169.75, &HYDRO_GAUGE1A, time, event, data, state).scaling = self.scaling(time, 169.75, 8.71);
     }
 
     fn reset_modify(&mut self) -> () {
@@ -1,6 +1,6 @@
 use crate::sim2::state::State;
 use crate::sim2::timeline::{ActionState, Timeline};
-use crate::sim2::attack::{Attack, CharacterAttack, AtkQueue};
+use crate::sim2::attack::{Attack, Scaling, CharacterAttack, AtkQueue};
 use crate::sim2::types::{CharacterAction, DamageType, Vision, FieldCharacterIndex, WeaponType, FieldEnergy, Particle, VecFieldEnergy, ToNaAction, PeriodicStack};
 use crate::sim2::element::{ElementalGauge, PHYSICAL_GAUGE, PYRO_GAUGE1A, PYRO_GAUGE2B, HYDRO_GAUGE1A, HYDRO_GAUGE2B, ELECTRO_GAUGE1A, ELECTRO_GAUGE2B, CRYO_GAUGE1A, CRYO_GAUGE2B, ANEMO_GAUGE1A, ANEMO_GAUGE2B, GEO_GAUGE1A, GEO_GAUGE2B, DENDRO_GAUGE1A, DENDRO_GAUGE2B};
 use crate::sim2::record::{CharacterRecord, CharacterData, Enemy};
@@ -61,10 +61,10 @@ impl Timeline for AratakiItto {
         } else if state.rel_time.press >= 10. {
             CharacterAction::PressSkill
         // use ca
-        } else if self.superstrength == 5 && state.rel_time.ca >= 0.5 {
+        } else if self.superstrength == 5 && state.rel_time.ca >= 0.5 && state.can_ca() {
             self.ca_combo = true;
             CharacterAction::Ca(0.)
-        } else if self.ca_combo && self.superstrength > 0 && state.rel_time.ca >= 0.5 {
+        } else if self.ca_combo && self.superstrength > 0 && state.rel_time.ca >= 0.5 && state.can_ca() {
             // TODO ca final?
             if self.superstrength == 1 {
                 self.ca_combo = false;
@@ -104,6 +104,10 @@ impl Timeline for AratakiItto {
         if self.superstrength > 5 {
             self.superstrength = 5;
         }
+        // Arataki Kesagiri consumes Superlative Superstrength instead of stamina
+        if self.superstrength > 0 {
+            state.reduce_stamina = 100.;
+        }
     }
 
     fn reset_timeline(&mut self) -> () {
@@ -233,9 +237,9 @@ impl CharacterAttack for Gorou {
         //     atk_queue.add_burst(110.34, &GEO_GAUGE1A, time + (i as f32) * 1.5, event, data, state);
         // }
         // C5
-        atk_queue.add_burst(208.71, &GEO_GAUGE2B, time, event, data, state);
+        atk_queue.add_burst(208.71, &GEO_GAUGE2B, time, event, data, state).scaling = Scaling::Def;
         for i in 0..6 {
-            atk_queue.add_burst(130.26, &GEO_GAUGE1A, time + (i as f32) * 1.5, event, data, state);
+            atk_queue.add_burst(130.26, &GEO_GAUGE1A, time + (i as f32) * 1.5, event, data, state).scaling = Scaling::Def;
         }
     }
 
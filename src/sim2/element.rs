@@ -133,6 +133,31 @@ pub enum ElementalReactionType {
 }
 
 impl ElementalReactionType {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Overloaded(_) => "Overloaded",
+            Shatter(_) => "Shatter",
+            ElectorCharged(_) => "Electro-Charged",
+            Swirl(_) => "Swirl",
+            Superconduct(_) => "Superconduct",
+            Vaporize(_) => "Vaporize",
+            Melt(_) => "Melt",
+            Burn(_) => "Burn",
+            Freeze(_) => "Freeze",
+            Crystallize(_) => "Crystallize",
+            Equalize(_) => "Equalize",
+            Neutralize(_) => "Neutralize",
+        }
+    }
+
+    // whether elements react. `Equalize` and `Neutralize` do not.
+    pub fn is_reaction(&self) -> bool {
+        match self {
+            Equalize(_) | Neutralize(_) => false,
+            _ => true,
+        }
+    }
+
     pub fn gauge_modifier(&self) -> f32 {
         match self {
             Overloaded(_) => -1.25,
@@ -0,0 +1,176 @@
+use crate::sim2::timeline::ActionState;
+use crate::sim2::types::CharacterAction;
+use crate::sim2::record::{CharacterData, Enemy};
+use crate::sim2::event::EnergyGain;
+use crate::sim2::trace::Tracer;
+use crate::sim2::party::{Party, PartyError, SimulationConfig};
+
+// energy that a member received from a character, weapon or artifact during
+// the simulation
+#[derive(Debug, PartialEq)]
+pub struct EnergySource {
+    pub source: &'static str,
+    // energy of particles before ER
+    pub particle: f32,
+    pub flat: f32,
+}
+
+#[derive(Debug)]
+pub struct MemberEnergy {
+    pub name: &'static str,
+    pub energy_cost: f32,
+    // ER% of the member including the base 100%
+    pub er: f32,
+    // the minimum ER% to use all bursts in time. infinite if particles are
+    // not enough at any ER.
+    pub required_er: f32,
+    // intended burst times
+    pub bursts: Vec<f32>,
+    // sources from the largest
+    pub sources: Vec<EnergySource>,
+}
+
+#[derive(Debug)]
+pub struct EnergyReport {
+    pub members: Vec<MemberEnergy>,
+}
+
+// bursts and energy of each member
+#[derive(Debug, Default)]
+struct EnergyTrace {
+    bursts: Vec<Vec<f32>>,
+    gains: Vec<EnergyGain>,
+}
+
+impl Tracer for EnergyTrace {
+    fn action(&mut self, data: &CharacterData, action: &CharacterAction, state: &ActionState) -> () {
+        let idx = data.idx.0;
+        while self.bursts.len() <= idx {
+            self.bursts.push(Vec::new());
+        }
+        if *action == CharacterAction::Burst {
+            self.bursts[idx].push(state.current_time);
+        }
+    }
+
+    fn energy(&mut self, gain: &EnergyGain) -> () {
+        self.gains.push(*gain);
+    }
+}
+
+// Energy received between two bursts, or before the first burst in addition
+// to the starting energy, has to reach the energy cost:
+//
+//      particles * ER + flat energy >= energy cost
+fn required_er(gains: &[&EnergyGain], bursts: &[f32], start: f32, energy_cost: f32) -> f32 {
+    let mut required: f32 = 100.;
+    let mut from = f32::NEG_INFINITY;
+    let mut energy = start;
+    for b in bursts.iter() {
+        let (particle, flat) = gains.iter()
+            .filter(|g| from <= g.time && g.time < *b)
+            .fold((0., 0.), |(p, f), g| (p + g.particle, f + g.flat));
+        let need = energy_cost - energy - flat;
+        if need > 0. {
+            required = required.max(if particle > 0. { 100. * need / particle } else { f32::INFINITY });
+        }
+        from = *b;
+        energy = 0.;
+    }
+    required
+}
+
+// Find the minimum ER of each member to use bursts at the same times as they
+// would with unlimited energy. Particles are counted before ER, so ER that
+// members gain during the simulation is a part of the requirement. Flat
+// energy that depends on ER (e.g. Raiden Shogun) is counted at the current
+// ER of the members.
+pub fn er_requirement(party: &mut Party, config: &SimulationConfig) -> Result<EnergyReport, PartyError> {
+    let mut ers: Vec<f32> = Vec::with_capacity(party.len());
+    for m in party.members.iter_mut() {
+        let mut artifact = m.artifact.0.clone();
+        artifact.equip(&m.loadout, &m.character.0.vision, m.character.0.name);
+        ers.push(100. + m.character.0.er + m.weapon.0.er + artifact.er);
+    }
+    let mut unlimited = *config;
+    unlimited.unlimited_energy = true;
+    let mut trace = EnergyTrace::default();
+    party.run_with(&unlimited, &mut Enemy::hilichurl(), &mut trace)?;
+    let EnergyTrace { bursts, gains } = trace;
+    let bursts: Vec<Vec<f32>> = (0..party.len()).map(|i| bursts.get(i).cloned().unwrap_or_default()).collect();
+    let required: Vec<f32> = party.members.iter().enumerate().map(|(i, m)| {
+        let energy_cost = m.character.0.energy_cost;
+        let start = m.start.energy.or(config.start_energy).unwrap_or(energy_cost);
+        let g: Vec<&EnergyGain> = gains.iter().filter(|g| g.idx.0 == i).collect();
+        required_er(&g, &bursts[i], start, energy_cost)
+    }).collect();
+    let mut members: Vec<MemberEnergy> = Vec::with_capacity(party.len());
+    for (i, m) in party.members.iter().enumerate() {
+        let mut sources: Vec<EnergySource> = Vec::new();
+        for g in gains.iter().filter(|g| g.idx.0 == i) {
+            match sources.iter_mut().find(|s| s.source == g.source) {
+                Some(s) => {
+                    s.particle += g.particle;
+                    s.flat += g.flat;
+                },
+                None => sources.push(EnergySource { source: g.source, particle: g.particle, flat: g.flat }),
+            }
+        }
+        sources.sort_by(|a, b| (b.particle + b.flat).partial_cmp(&(a.particle + a.flat)).unwrap());
+        members.push(MemberEnergy {
+            name: m.character.0.name,
+            energy_cost: m.character.0.energy_cost,
+            er: ers[i],
+            required_er: required[i],
+            bursts: bursts[i].clone(),
+            sources,
+        });
+    }
+    Ok(EnergyReport {
+        members,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sim2::party::PartyMember;
+    use crate::sim2::attack::DamageResultUtil;
+
+    #[test]
+    fn diluc_bennett() {
+        let mut config = SimulationConfig::default();
+        config.simulation_time = 60.;
+        let mut party = Party::new()
+            .member(PartyMember::find("Diluc", "Prototype Archaic", "Gladiator's Finale").unwrap())
+            .member(PartyMember::find("Bennett", "Prototype Rancour", "Noblesse Oblige").unwrap());
+        let dmg = party.run(&config, &mut Enemy::hilichurl()).unwrap().total_damage();
+        let report = er_requirement(&mut party, &config).unwrap();
+        let diluc = &report.members[0];
+        let bennett = &report.members[1];
+        assert_eq!(diluc.name, "Diluc");
+        assert_eq!(diluc.er, 100.);
+        // bursts are used more often than with 100% ER
+        assert!(diluc.bursts.len() > 2);
+        assert!(diluc.required_er > 100. && diluc.required_er.is_finite());
+        assert_eq!(diluc.sources[0].source, "Diluc");
+        assert!(bennett.sources.iter().any(|s| s.source == "Bennett"));
+        // the party is restored
+        assert_eq!(party.run(&config, &mut Enemy::hilichurl()).unwrap().total_damage(), dmg);
+    }
+
+    #[test]
+    fn required_er_is_enough() {
+        let mut config = SimulationConfig::default();
+        config.simulation_time = 60.;
+        let mut party = Party::new()
+            .member(PartyMember::find("Diluc", "Prototype Archaic", "Gladiator's Finale").unwrap());
+        let report = er_requirement(&mut party, &config).unwrap();
+        let required = report.members[0].required_er;
+        // the intended bursts are used with the required ER
+        party.members[0].artifact.0.er += required - 100. + 0.1;
+        let mut trace = EnergyTrace::default();
+        party.run_with(&config, &mut Enemy::hilichurl(), &mut trace).unwrap();
+        assert_eq!(trace.bursts[0], report.members[0].bursts);
+    }
+}
@@ -0,0 +1,60 @@
+use crate::sim2::types::FieldCharacterIndex;
+
+// Events delivered to characters, weapons and artifacts of all members by
+// `event` of `CharacterAttack` and `WeaponAttack`. `Burst`, `Particle` and
+// `Swap` are recorded by `simulate::decide_action` and delivered before
+// `attack` of the same time. the others are delivered after each hit and
+// before `modify` of the next attack, which is after `reset_attack`.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Event {
+    // a member used the burst and spent `energy`
+    Burst { idx: FieldCharacterIndex, energy: f32 },
+    // a member received `energy` from a particle or an orb
+    Particle { idx: FieldCharacterIndex, energy: f32 },
+    // an off-field member took the field to use a skill or a burst, and left
+    // it after the action
+    Swap { from: FieldCharacterIndex, to: FieldCharacterIndex },
+    // a skill of a member hit the enemy
+    SkillHit { idx: FieldCharacterIndex },
+    // a hit of a member was critical. damages are expected values, so `rate`
+    // is the probability.
+    Crit { idx: FieldCharacterIndex, rate: f32 },
+    // a hit of a member triggered a reaction
+    Reaction { idx: FieldCharacterIndex, name: &'static str },
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct TimedEvent {
+    pub time: f32,
+    pub event: Event,
+}
+
+impl TimedEvent {
+    pub fn new(time: f32, event: Event) -> Self {
+        Self {
+            time,
+            event,
+        }
+    }
+}
+
+// energy received by a member from a particle, an orb or a flat restoration.
+// `particle` is the energy of particles before ER, which depends on whether
+// the member is on the field, and `er` is the ER multiplier of the member
+// then. flat energy is not affected by ER.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct EnergyGain {
+    pub time: f32,
+    pub idx: FieldCharacterIndex,
+    // the name of the character, weapon or artifact that generated the energy
+    pub source: &'static str,
+    pub particle: f32,
+    pub flat: f32,
+    pub er: f32,
+}
+
+impl EnergyGain {
+    pub fn energy(&self) -> f32 {
+        self.particle * self.er + self.flat
+    }
+}
@@ -0,0 +1,266 @@
+use crate::sim2::attack::DamageResultUtil;
+use crate::sim2::party::{Party, PartyError, SimulationConfig};
+use crate::sim2::record::{Artifact, ArtifactLoadout, Enemy, Substat, SubstatRolls};
+
+fn total_damage(party: &mut Party, config: &SimulationConfig) -> Result<f32, PartyError> {
+    Ok(party.run(config, &mut Enemy::hilichurl())?.total_damage())
+}
+
+// each of 5 artifacts can roll a substat up to 6 times, unless the main stat
+// is the same.
+pub fn max_rolls(substat: &Substat, loadout: &ArtifactLoadout) -> f32 {
+    let n = loadout.iter().filter(|m| substat.is_main_stat(m)).count();
+    6. * (5 - n) as f32
+}
+
+#[derive(Debug)]
+pub struct SubstatReport {
+    pub rolls: SubstatRolls,
+    pub damage: f32,
+    // damage gained by one more roll of each substat
+    pub marginal_gain: Vec<(Substat, f32)>,
+}
+
+// distribute `budget` rolls to substats of the on-field member one at a time,
+// picking the substat that increases the total damage most, then refine the
+// distribution by moving rolls between substats.
+pub fn optimize_substats(party: &mut Party, budget: f32, config: &SimulationConfig) -> Result<SubstatReport, PartyError> {
+    if party.members.is_empty() {
+        return Err(PartyError::new("party has no members."));
+    }
+    let loadout = party.members[0].loadout;
+    let mut rolls = SubstatRolls::new();
+    party.members[0].artifact.0.substats = rolls;
+    let mut damage = total_damage(party, config)?;
+    while rolls.total() + 1. <= budget {
+        let mut best: Option<(Substat, f32)> = None;
+        for s in Substat::ALL.iter() {
+            if rolls.get(s) + 1. > max_rolls(s, &loadout) {
+                continue;
+            }
+            let mut candidate = rolls;
+            candidate.add(s, 1.);
+            party.members[0].artifact.0.substats = candidate;
+            let dmg = total_damage(party, config)?;
+            match best {
+                Some((_, d)) if d >= dmg => (),
+                _ => best = Some((*s, dmg)),
+            }
+        }
+        if let Some((s, dmg)) = best {
+            rolls.add(&s, 1.);
+            damage = dmg;
+        } else {
+            break;
+        }
+    }
+    // Greedy choices undervalue stats that multiply each other (e.g. CR and
+    // CD), so move single rolls between substats while the damage increases.
+    loop {
+        let mut best: Option<(SubstatRolls, f32)> = None;
+        for from in Substat::ALL.iter() {
+            if rolls.get(from) < 1. {
+                continue;
+            }
+            for to in Substat::ALL.iter() {
+                if from == to || rolls.get(to) + 1. > max_rolls(to, &loadout) {
+                    continue;
+                }
+                let mut candidate = rolls;
+                candidate.add(from, -1.).add(to, 1.);
+                party.members[0].artifact.0.substats = candidate;
+                let dmg = total_damage(party, config)?;
+                match best {
+                    Some((_, d)) if d >= dmg => (),
+                    _ => best = Some((candidate, dmg)),
+                }
+            }
+        }
+        match best {
+            Some((candidate, dmg)) if dmg > damage => {
+                rolls = candidate;
+                damage = dmg;
+            },
+            _ => break,
+        }
+    }
+    let mut marginal_gain: Vec<(Substat, f32)> = Vec::with_capacity(Substat::ALL.len());
+    for s in Substat::ALL.iter() {
+        let mut candidate = rolls;
+        candidate.add(s, 1.);
+        party.members[0].artifact.0.substats = candidate;
+        let dmg = total_damage(party, config)?;
+        marginal_gain.push((*s, dmg - damage));
+    }
+    party.members[0].artifact.0.substats = rolls;
+    Ok(SubstatReport {
+        rolls,
+        damage,
+        marginal_gain,
+    })
+}
+
+// Stats of artifacts that are added by `State::init`, e.g. for weights of
+// stats and ley line disorders.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Stat {
+    Atk, Cr, Cd, Em, Er,
+    Physical, Pyro, Cryo, Hydro, Electro, Anemo, Geo, Dendro,
+}
+
+impl Stat {
+    pub const ALL: [Stat; 13] = [
+        Stat::Atk, Stat::Cr, Stat::Cd, Stat::Em, Stat::Er,
+        Stat::Physical, Stat::Pyro, Stat::Cryo, Stat::Hydro, Stat::Electro, Stat::Anemo, Stat::Geo, Stat::Dendro,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Stat::Atk => "ATK%",
+            Stat::Cr => "CR",
+            Stat::Cd => "CD",
+            Stat::Em => "EM",
+            Stat::Er => "ER",
+            Stat::Physical => "Physical DMG",
+            Stat::Pyro => "Pyro DMG",
+            Stat::Cryo => "Cryo DMG",
+            Stat::Hydro => "Hydro DMG",
+            Stat::Electro => "Electro DMG",
+            Stat::Anemo => "Anemo DMG",
+            Stat::Geo => "Geo DMG",
+            Stat::Dendro => "Dendro DMG",
+        }
+    }
+
+    // about one substat roll, so that the difference is not lost in the
+    // rounding of the simulation, e.g. of particles.
+    pub fn step(&self) -> f32 {
+        match self {
+            Stat::Cr => 4.,
+            Stat::Cd => 8.,
+            Stat::Em => 20.,
+            _ => 6.,
+        }
+    }
+
+    // names in files, e.g. "atk" or "pyro"
+    pub fn parse(key: &str) -> Option<Self> {
+        match key {
+            "atk" => Some(Stat::Atk),
+            "cr" => Some(Stat::Cr),
+            "cd" => Some(Stat::Cd),
+            "em" => Some(Stat::Em),
+            "er" => Some(Stat::Er),
+            "physical" => Some(Stat::Physical),
+            "pyro" => Some(Stat::Pyro),
+            "cryo" => Some(Stat::Cryo),
+            "hydro" => Some(Stat::Hydro),
+            "electro" => Some(Stat::Electro),
+            "anemo" => Some(Stat::Anemo),
+            "geo" => Some(Stat::Geo),
+            "dendro" => Some(Stat::Dendro),
+            _ => None,
+        }
+    }
+
+    // the value in an artifact that is added to the stat by `State::init`
+    pub fn value<'a>(&self, ar: &'a mut Artifact) -> &'a mut f32 {
+        match self {
+            Stat::Atk => &mut ar.atk,
+            Stat::Cr => &mut ar.cr,
+            Stat::Cd => &mut ar.cd,
+            Stat::Em => &mut ar.em,
+            Stat::Er => &mut ar.er,
+            Stat::Physical => &mut ar.physical_dmg,
+            Stat::Pyro => &mut ar.pyro_dmg,
+            Stat::Cryo => &mut ar.cryo_dmg,
+            Stat::Hydro => &mut ar.hydro_dmg,
+            Stat::Electro => &mut ar.electro_dmg,
+            Stat::Anemo => &mut ar.anemo_dmg,
+            Stat::Geo => &mut ar.geo_dmg,
+            Stat::Dendro => &mut ar.dendro_dmg,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct StatWeightReport {
+    pub damage: f32,
+    // damage gained by one unit of each stat, from the largest
+    pub weights: Vec<(Stat, f32)>,
+}
+
+// Add each stat to the artifact of `member` one at a time and measure the
+// total damage of the party. The whole simulation is run again, so that ER
+// changes the timeline.
+pub fn stat_weights(party: &mut Party, member: usize, config: &SimulationConfig) -> Result<StatWeightReport, PartyError> {
+    if member >= party.len() {
+        return Err(PartyError::new(&format!("member {} is not in the party of {} members.", member, party.len())));
+    }
+    let damage = total_damage(party, config)?;
+    let mut weights: Vec<(Stat, f32)> = Vec::with_capacity(Stat::ALL.len());
+    for s in Stat::ALL.iter() {
+        let step = s.step();
+        let artifact = party.members[member].artifact.0.clone();
+        *s.value(&mut party.members[member].artifact.0) += step;
+        let dmg = total_damage(party, config);
+        party.members[member].artifact.0 = artifact;
+        let dmg = dmg?;
+        weights.push((*s, (dmg - damage) / step));
+    }
+    weights.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap());
+    Ok(StatWeightReport {
+        damage,
+        weights,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sim2::record::MainStat;
+
+    #[test]
+    fn diluc_substats() {
+        let mut config = SimulationConfig::default();
+        config.simulation_time = 6.0;
+        let names: Vec<String> = ["Diluc", "Prototype Archaic", "Gladiator's Finale"].iter().map(|x| x.to_string()).collect();
+        let mut party = Party::find(&names).unwrap();
+        let report = optimize_substats(&mut party, 4., &config).unwrap();
+        assert_eq!(report.rolls.total(), 4.);
+        assert_eq!(report.marginal_gain.len(), Substat::ALL.len());
+        // neither HP nor DEF scales Diluc's damage
+        assert_eq!(report.rolls.hp, 0.);
+        assert_eq!(report.rolls.def, 0.);
+        party.members[0].artifact.0.substats = SubstatRolls::new();
+        let zero = total_damage(&mut party, &config).unwrap();
+        assert!(report.damage > zero);
+    }
+
+    #[test]
+    fn max_rolls_of_main_stats() {
+        let loadout = ArtifactLoadout::default();
+        assert_eq!(max_rolls(&Substat::Cr, &loadout), 30.);
+        let loadout = ArtifactLoadout::new(Some(MainStat::Atk), None, Some(MainStat::Cr));
+        assert_eq!(max_rolls(&Substat::Atk, &loadout), 24.);
+        assert_eq!(max_rolls(&Substat::Cr, &loadout), 24.);
+    }
+
+    #[test]
+    fn diluc_stat_weights() {
+        let mut config = SimulationConfig::default();
+        config.simulation_time = 10.0;
+        let names: Vec<String> = ["Diluc", "Prototype Archaic", "Gladiator's Finale"].iter().map(|x| x.to_string()).collect();
+        let mut party = Party::find(&names).unwrap();
+        let before = party.members[0].artifact.0.atk;
+        let report = stat_weights(&mut party, 0, &config).unwrap();
+        assert_eq!(report.weights.len(), Stat::ALL.len());
+        // stats are restored
+        assert_eq!(party.members[0].artifact.0.atk, before);
+        let weight = |stat: Stat| report.weights.iter().find(|(s, _)| *s == stat).unwrap().1;
+        assert!(weight(Stat::Pyro) > 0.);
+        assert_eq!(weight(Stat::Hydro), 0.);
+        assert!(report.weights[0].1 >= report.weights[1].1);
+        assert!(stat_weights(&mut party, 1, &config).is_err());
+    }
+}
@@ -0,0 +1,422 @@
+use std::error::Error;
+use std::fmt;
+use std::convert::TryInto;
+
+use crate::sim2::characters;
+use crate::sim2::weapons;
+use crate::sim2::artifact;
+use crate::sim2::simulate;
+use crate::sim2::timeline::ActionState;
+use crate::sim2::attack::DamageResult;
+use crate::sim2::simulate::History;
+use crate::sim2::trace::{Tracer, NoTrace};
+use crate::sim2::record::{TimelineMember, FieldMember, CharacterData, CharacterRecord, WeaponRecord, Artifact, ArtifactLoadout, Enemy};
+use crate::sim2::types::ParticleMode;
+
+#[derive(Debug)]
+pub struct PartyError {
+    details: String
+}
+
+impl PartyError {
+    pub fn new(msg: &str) -> PartyError {
+        PartyError { details: msg.to_string() }
+    }
+}
+
+impl fmt::Display for PartyError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f,"{}",self.details)
+    }
+}
+
+impl Error for PartyError {
+    fn description(&self) -> &str {
+        &self.details
+    }
+}
+
+// conditions of a member at the beginning of simulations. `None` means the
+// default, e.g. `SimulationConfig::start_energy` for `energy`.
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+pub struct StartState {
+    pub energy: Option<f32>,
+    // seconds since the last skill and burst, which are set to
+    // `ActionState::rel_time`. buffs of the last skill and burst are not
+    // active, and they were used at least one unit time before the start.
+    pub skill: Option<f32>,
+    pub burst: Option<f32>,
+    // see `WeaponAttack::set_stacks`
+    pub weapon_stacks: Option<f32>,
+    pub artifact_stacks: Option<f32>,
+}
+
+impl StartState {
+    fn apply(&self, state: &mut ActionState, unit_time: f32) -> () {
+        if let Some(energy) = self.energy {
+            state.energy = energy;
+        }
+        // `abs_time` of 0 means that the action is used at the start
+        if let Some(t) = self.skill {
+            let t = t.max(unit_time);
+            state.rel_time.press = t;
+            state.rel_time.hold = t;
+            state.abs_time.press = -t;
+            state.abs_time.hold = -t;
+        }
+        if let Some(t) = self.burst {
+            let t = t.max(unit_time);
+            state.rel_time.burst = t;
+            state.abs_time.burst = -t;
+        }
+    }
+}
+
+// a character with a weapon and artifacts
+pub struct PartyMember {
+    pub character: (CharacterRecord, characters::CharacterUnion),
+    pub weapon: (WeaponRecord, weapons::WeaponUnion),
+    pub artifact: (Artifact, artifact::ArtifactUnion),
+    pub loadout: ArtifactLoadout,
+    pub start: StartState,
+}
+
+impl PartyMember {
+    pub fn new(character: (CharacterRecord, characters::CharacterUnion), weapon: (WeaponRecord, weapons::WeaponUnion), artifact: (Artifact, artifact::ArtifactUnion)) -> Self {
+        Self {
+            character,
+            weapon,
+            artifact,
+            loadout: ArtifactLoadout::default(),
+            start: StartState::default(),
+        }
+    }
+
+    // find records by their names, e.g. "Diluc", "Prototype Archaic" and
+    // "Gladiator's Finale"
+    pub fn find(character: &str, weapon: &str, artifact: &str) -> Result<Self, Box<dyn Error>> {
+        let c = characters::all().into_iter().find(|(r, _)| r.name == character);
+        let w = weapons::all().into_iter().find(|(r, _)| r.name == weapon);
+        let a = artifact::all().into_iter().find(|(r, _)| r.name == artifact);
+        match (c, w, a) {
+            (Some(c), Some(w), Some(a)) => Ok(Self::new(c, w, a)),
+            (None, _, _) => Err(Box::new(PartyError::new(&format!("character '{}' was not found.", character)))),
+            (_, None, _) => Err(Box::new(PartyError::new(&format!("weapon '{}' was not found.", weapon)))),
+            (_, _, None) => Err(Box::new(PartyError::new(&format!("artifact '{}' was not found.", artifact)))),
+        }
+    }
+
+    pub fn loadout(mut self, loadout: ArtifactLoadout) -> Self { self.loadout = loadout; self }
+    pub fn start(mut self, start: StartState) -> Self { self.start = start; self }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct SimulationConfig {
+    pub simulation_time: f32,
+    pub unit_time: f32,
+    // `None` means full energy
+    pub start_energy: Option<f32>,
+    // bursts are used whenever they are off cooldown
+    pub unlimited_energy: bool,
+    pub particle: ParticleMode,
+    // seconds until particles reach members
+    pub particle_delay: f32,
+}
+
+impl Default for SimulationConfig {
+    fn default() -> Self {
+        Self {
+            simulation_time: 20.0,
+            unit_time: 0.2,
+            start_energy: None,
+            unlimited_energy: false,
+            particle: ParticleMode::Expected,
+            particle_delay: 0.,
+        }
+    }
+}
+
+// One to four members. The first member is the on-field character.
+pub struct Party {
+    pub members: Vec<PartyMember>,
+}
+
+impl Party {
+    pub fn new() -> Self {
+        Self {
+            members: Vec::new(),
+        }
+    }
+
+    pub fn member(mut self, member: PartyMember) -> Self { self.members.push(member); self }
+
+    // `names` is a list of character, weapon and artifact names.
+    pub fn find(names: &[String]) -> Result<Self, Box<dyn Error>> {
+        if names.is_empty() || names.len() % 3 != 0 || names.len() > 12 {
+            return Err(Box::new(PartyError::new("party should be 1 to 4 sets of character, weapon and artifact names.")));
+        }
+        let mut party = Self::new();
+        for x in names.chunks(3) {
+            party = party.member(PartyMember::find(&x[0], &x[1], &x[2])?);
+        }
+        Ok(party)
+    }
+
+    pub fn len(&self) -> usize {
+        self.members.len()
+    }
+
+    // Run the simulation once. Artifacts are equipped with the loadout of each
+    // member during the simulation. The party should have 1 to 4 members.
+    pub fn run(&mut self, config: &SimulationConfig, enemy: &mut Enemy) -> Result<Vec<DamageResult>, PartyError> {
+        self.run_with(config, enemy, &mut NoTrace)
+    }
+
+    // `run` that reports events of the simulation to `tracer`
+    pub fn run_with<T: Tracer>(&mut self, config: &SimulationConfig, enemy: &mut Enemy, tracer: &mut T) -> Result<Vec<DamageResult>, PartyError> {
+        if self.members.is_empty() || self.members.len() > 4 {
+            return Err(PartyError::new(&format!("party should have 1 to 4 members, not {}.", self.members.len())));
+        }
+        let artifacts: Vec<Artifact> = self.members.iter().map(|m| m.artifact.0.clone()).collect();
+        for m in self.members.iter_mut() {
+            m.artifact.0.equip(&m.loadout, &m.character.0.vision, m.character.0.name);
+        }
+        let dmg = match self.members.len() {
+            1 => run_n::<_, 1>(&mut self.members, config, enemy, tracer),
+            2 => run_n::<_, 2>(&mut self.members, config, enemy, tracer),
+            3 => run_n::<_, 3>(&mut self.members, config, enemy, tracer),
+            4 => run_n::<_, 4>(&mut self.members, config, enemy, tracer),
+            _ => unreachable!(),
+        };
+        for (m, a) in self.members.iter_mut().zip(artifacts) {
+            m.artifact.0 = a;
+        }
+        Ok(dmg)
+    }
+}
+
+fn to_array<T, const N: usize>(v: Vec<T>) -> [T; N] {
+    match v.try_into() {
+        Ok(a) => a,
+        Err(_) => unreachable!(),
+    }
+}
+
+fn run_n<T: Tracer, const N: usize>(party: &mut [PartyMember], config: &SimulationConfig, enemy: &mut Enemy, tracer: &mut T) -> Vec<DamageResult> {
+    let mut history = History::<N>::new(config.simulation_time, config.unit_time);
+    history.unlimited_energy = config.unlimited_energy;
+    history.particle = config.particle;
+    history.particle_delay = config.particle_delay;
+    let mut data: Vec<CharacterData> = Vec::with_capacity(N);
+    let mut unions = Vec::with_capacity(N);
+    let mut starts = Vec::with_capacity(N);
+    for (i, m) in party.iter_mut().enumerate() {
+        let PartyMember { character: (cr, ca), weapon: (wr, wa), artifact: (ar, aa), start, .. } = m;
+        if let Some(stacks) = start.weapon_stacks {
+            wa.field().set_stacks(stacks);
+        }
+        if let Some(stacks) = start.artifact_stacks {
+            aa.field().set_stacks(stacks);
+        }
+        data.push(CharacterData::new(i, cr, wr, ar));
+        unions.push((ca, wa, aa));
+        starts.push(*start);
+    }
+    let mut data: [CharacterData; N] = to_array(data);
+    {
+        let mut members: [TimelineMember; N] = to_array(unions.iter_mut().map(|(ca, wa, aa)| TimelineMember {
+            character: ca.timeline(),
+            weapon: wa.timeline(),
+            artifact: aa.timeline(),
+        }).collect());
+        let mut states = [ActionState::new(); N];
+        for ((s, d), start) in states.iter_mut().zip(data.iter()).zip(starts.iter()) {
+            s.energy = match config.start_energy {
+                Some(energy) => energy,
+                None => d.character.energy_cost,
+            };
+            start.apply(s, config.unit_time);
+        }
+        simulate::decide_action(&mut history, &mut members, &mut states, &mut data);
+        for m in members.iter_mut() {
+            m.character.reset_timeline();
+            m.weapon.reset_timeline();
+            m.artifact.reset_timeline();
+        }
+    }
+    let mut members: [FieldMember; N] = to_array(unions.iter_mut().map(|(ca, wa, aa)| FieldMember {
+        character: ca.field(),
+        weapon: wa.field(),
+        artifact: aa.field(),
+    }).collect());
+    let dmg = simulate::calculate_damage_with(&mut history, &mut members, &mut data, enemy, tracer);
+    for m in members.iter_mut() {
+        m.character.reset_modify();
+        m.weapon.reset_modify();
+        m.artifact.reset_modify();
+    }
+    dmg
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sim2::attack::DamageResultUtil;
+    use crate::sim2::types::DamageType;
+    use crate::sim2::event::EnergyGain;
+    use crate::sim2::types::approx_equal;
+
+    // energy received by members
+    #[derive(Default)]
+    struct Gains(Vec<EnergyGain>);
+
+    impl Tracer for Gains {
+        fn energy(&mut self, gain: &EnergyGain) -> () {
+            self.0.push(*gain);
+        }
+    }
+
+    #[test]
+    fn start_state_at_zero() {
+        let start = StartState { skill: Some(0.), burst: Some(0.), ..StartState::default() };
+        let mut state = ActionState::new();
+        start.apply(&mut state, 0.2);
+        // the skill and the burst were not used at the start
+        assert!(!state.did_skill());
+        assert!(!state.did_burst());
+        assert_eq!(state.rel_time.burst, 0.2);
+    }
+
+    #[test]
+    fn run_twice() {
+        let config = SimulationConfig::default();
+        let mut party = Party::new()
+            .member(PartyMember::find("Diluc", "Prototype Archaic", "Gladiator's Finale").unwrap())
+            .member(PartyMember::find("Bennett", "Prototype Rancour", "Noblesse Oblige").unwrap());
+        party.members[0].artifact.0.substats.cr = 5.;
+        let stats = |p: &Party| (p.members[0].artifact.0.flat_atk, p.members[0].artifact.0.atk, p.members[0].artifact.0.cr);
+        let before = stats(&party);
+        let dmg1 = party.run(&config, &mut Enemy::hilichurl()).unwrap().total_damage();
+        let dmg2 = party.run(&config, &mut Enemy::hilichurl()).unwrap().total_damage();
+        assert!(dmg1 > 0.);
+        // members are reset after each run
+        assert_eq!(dmg1, dmg2);
+        assert_eq!(stats(&party), before);
+    }
+
+    #[test]
+    fn size_of_party() {
+        let config = SimulationConfig::default();
+        assert!(Party::new().run(&config, &mut Enemy::hilichurl()).is_err());
+        let mut party = Party::new();
+        for _ in 0..5 {
+            party = party.member(PartyMember::find("Diluc", "Prototype Archaic", "Gladiator's Finale").unwrap());
+        }
+        assert!(party.run(&config, &mut Enemy::hilichurl()).is_err());
+    }
+
+    #[test]
+    fn resolve_stacks() {
+        let mut config = SimulationConfig::default();
+        config.simulation_time = 4.0;
+        let burst = |party: &mut Party| party.run(&config, &mut Enemy::hilichurl()).unwrap().iter()
+            .filter(|d| d.name == "Raiden Shogun" && d.kind == DamageType::Burst)
+            .map(|d| d.damage).fold(0., f32::max);
+        let mut solo = Party::new()
+            .member(PartyMember::find("Raiden Shogun", "The Catch", "Emblem of Severed Fate").unwrap());
+        let mut party = Party::new()
+            .member(PartyMember::find("Raiden Shogun", "The Catch", "Emblem of Severed Fate").unwrap())
+            .member(PartyMember::find("Xiangling", "The Catch", "Emblem of Severed Fate").unwrap());
+        // Xiangling uses the burst before Raiden, which gives resolve stacks
+        assert!(burst(&mut party) > burst(&mut solo));
+    }
+
+    #[test]
+    fn particles() {
+        let mut config = SimulationConfig::default();
+        config.start_energy = Some(0.);
+        let energy = |party: &mut Party, config: &SimulationConfig| {
+            let mut gains = Gains::default();
+            party.run_with(config, &mut Enemy::hilichurl(), &mut gains).unwrap();
+            gains.0
+        };
+        let mut party = Party::new()
+            .member(PartyMember::find("Diluc", "Prototype Archaic", "Gladiator's Finale").unwrap())
+            .member(PartyMember::find("Bennett", "Prototype Rancour", "Noblesse Oblige").unwrap());
+        let instant = energy(&mut party, &config);
+        config.particle_delay = 1.;
+        let delayed = energy(&mut party, &config);
+        // Bennett's particles land after he leaves the field
+        let first = |gains: &[EnergyGain]| *gains.iter().find(|g| g.source == "Bennett" && g.idx.0 == 0).unwrap();
+        assert!((first(&delayed).time - first(&instant).time - 1.).abs() < 0.01);
+        // sampled numbers are reproducible
+        config.particle = ParticleMode::Sampled(3);
+        let sampled = energy(&mut party, &config);
+        assert_eq!(sampled, energy(&mut party, &config));
+    }
+
+    #[test]
+    fn party_size() {
+        let config = SimulationConfig::default();
+        let bennett = |names: &[&str]| {
+            let names: Vec<String> = names.iter().map(|x| x.to_string()).collect();
+            let mut party = Party::find(&names).unwrap();
+            let mut gains = Gains::default();
+            party.run_with(&config, &mut Enemy::hilichurl(), &mut gains).unwrap();
+            gains.0.iter().filter(|g| g.idx.0 == 1 && g.source == "Diluc").map(|g| g.particle).sum::<f32>()
+        };
+        let duo = ["Diluc", "Prototype Archaic", "Gladiator's Finale", "Bennett", "Prototype Rancour", "Noblesse Oblige"];
+        let four = [&duo[..], &["Xiangling", "The Catch", "Emblem of Severed Fate", "Sucrose", "Sacrificial Fragments", "Viridescent Venerer"]].concat();
+        // Bennett receives less energy from Diluc's particles in a larger party
+        let (a, b) = (bennett(&duo), bennett(&four));
+        assert!(a > 0.);
+        assert!(approx_equal(b / a, 0.6 / 0.8, 4));
+    }
+
+    #[test]
+    fn flat_energy_recipients() {
+        let mut config = SimulationConfig::default();
+        config.simulation_time = 10.;
+        let mut party = Party::new()
+            .member(PartyMember::find("Raiden Shogun", "The Catch", "Emblem of Severed Fate").unwrap())
+            .member(PartyMember::find("Xiangling", "The Catch", "Emblem of Severed Fate").unwrap());
+        let mut gains = Gains::default();
+        party.run_with(&config, &mut Enemy::hilichurl(), &mut gains).unwrap();
+        let flat = |idx: usize| gains.0.iter().filter(|g| g.idx.0 == idx && g.source == "Raiden Shogun").map(|g| g.flat).sum::<f32>();
+        // the burst of the Raiden Shogun restores energy of the others only
+        assert_eq!(flat(0), 0.);
+        assert!(flat(1) > 0.);
+    }
+
+    #[test]
+    fn absorbed_and_on_field_recipients() {
+        let mut config = SimulationConfig::default();
+        config.simulation_time = 10.;
+        let mut party = Party::new()
+            .member(PartyMember::find("Xiangling", "The Catch", "Emblem of Severed Fate").unwrap())
+            .member(PartyMember::find("Venti", "The Stringless", "Noblesse Oblige").unwrap())
+            .member(PartyMember::find("Fischl", "The Stringless", "Noblesse Oblige").unwrap())
+            .member(PartyMember::find("Traveler Electro", "Sacrificial Sword", "Noblesse Oblige").unwrap());
+        let mut gains = Gains::default();
+        party.run_with(&config, &mut Enemy::hilichurl(), &mut gains).unwrap();
+        let flat = |source: &str, idx: usize| gains.0.iter().filter(|g| g.idx.0 == idx && g.source == source).map(|g| g.flat).sum::<f32>();
+        // the burst of Venti restores energy of Venti and the pyro member
+        assert!(flat("Venti", 0) > 0.);
+        assert!(flat("Venti", 1) > 0.);
+        assert_eq!(flat("Venti", 2), 0.);
+        assert_eq!(flat("Venti", 3), 0.);
+        // the amulets and the burst of the Traveler restore energy of the
+        // member on the field only
+        assert!(flat("Traveler Electro", 0) > 0.);
+        for i in 1..4 {
+            assert_eq!(flat("Traveler Electro", i), 0.);
+        }
+    }
+
+    #[test]
+    fn not_found() {
+        assert!(PartyMember::find("Diluc", "Prototype Archaic", "Unknown").is_err());
+        let names: Vec<String> = ["Diluc", "Prototype Archaic"].iter().map(|x| x.to_string()).collect();
+        assert!(Party::find(&names).is_err());
+    }
+}
@@ -1,5 +1,5 @@
 use crate::sim2::types::{CharacterAction, DamageType, Vision, FieldCharacterIndex, FieldEnergy, WeaponType, Preference};
-use crate::sim2::element::{ElementalGauge, ElementalReactionType, ElementalReaction, PYRO_GAUGE1A, HYDRO_GAUGE1A, ELECTRO_GAUGE1A, CRYO_GAUGE1A, PHYSICAL_GAUGE};
+use crate::sim2::element::{ElementalGauge, ElementalGaugeDecay, ElementalReactionType, ElementalReaction, PYRO_GAUGE1A, HYDRO_GAUGE1A, ELECTRO_GAUGE1A, CRYO_GAUGE1A, PHYSICAL_GAUGE};
 use crate::sim2::state::State;
 use crate::sim2::timeline::Timeline;
 use crate::sim2::attack::{Attack, CharacterAttack, WeaponAttack};
@@ -109,6 +109,7 @@ pub struct WeaponRecord {
     pub ca_dmg: f32,
     pub skill_dmg: f32,
     pub burst_dmg: f32,
+    pub plunge_dmg: f32,
     pub all_dmg: f32,
     pub physical_dmg: f32,
     pub elemental_dmg: f32,
@@ -140,6 +141,7 @@ impl Default for WeaponRecord {
             ca_dmg: 0.0,
             skill_dmg: 0.0,
             burst_dmg: 0.0,
+            plunge_dmg: 0.0,
             all_dmg: 0.0,
             physical_dmg: 0.0,
             elemental_dmg: 0.0,
@@ -175,6 +177,7 @@ impl WeaponRecord {
     pub fn ca_dmg(mut self, ca_dmg: f32) -> Self { self.ca_dmg = ca_dmg; self }
     pub fn skill_dmg(mut self, skill_dmg: f32) -> Self { self.skill_dmg = skill_dmg; self }
     pub fn burst_dmg(mut self, burst_dmg: f32) -> Self { self.burst_dmg = burst_dmg; self }
+    pub fn plunge_dmg(mut self, plunge_dmg: f32) -> Self { self.plunge_dmg = plunge_dmg; self }
     pub fn all_dmg(mut self, all_dmg: f32) -> Self { self.all_dmg = all_dmg; self }
     pub fn physical_dmg(mut self, physical_dmg: f32) -> Self { self.physical_dmg = physical_dmg; self }
     pub fn elemental_dmg(mut self, elemental_dmg: f32) -> Self { self.elemental_dmg = elemental_dmg; self }
@@ -187,7 +190,7 @@ impl WeaponRecord {
     pub fn dendro_dmg(mut self, dendro_dmg: f32) -> Self { self.dendro_dmg = dendro_dmg; self }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Artifact {
     pub name: &'static str,
     pub version: f32,
@@ -205,6 +208,7 @@ pub struct Artifact {
     pub ca_dmg: f32,
     pub skill_dmg: f32,
     pub burst_dmg: f32,
+    pub plunge_dmg: f32,
     pub all_dmg: f32,
     pub physical_dmg: f32,
     pub elemental_dmg: f32,
@@ -217,6 +221,8 @@ pub struct Artifact {
     pub dendro_dmg: f32,
     pub amplifying_bonus: f32,
     pub transformative_bonus: f32,
+    pub healing_bonus: f32,
+    pub substats: SubstatRolls,
 }
 
 impl Default for Artifact {
@@ -238,6 +244,7 @@ impl Default for Artifact {
             ca_dmg: 0.0,
             skill_dmg: 0.0,
             burst_dmg: 0.0,
+            plunge_dmg: 0.0,
             all_dmg: 0.0,
             physical_dmg: 0.0,
             elemental_dmg: 0.0,
@@ -250,6 +257,8 @@ impl Default for Artifact {
             dendro_dmg: 0.0,
             amplifying_bonus: 0.0,
             transformative_bonus: 0.0,
+            healing_bonus: 0.0,
+            substats: SubstatRolls::default(),
         }
     }
 }
@@ -275,6 +284,7 @@ impl Artifact {
     pub fn ca_dmg(mut self, ca_dmg: f32) -> Self { self.ca_dmg = ca_dmg; self }
     pub fn skill_dmg(mut self, skill_dmg: f32) -> Self { self.skill_dmg = skill_dmg; self }
     pub fn burst_dmg(mut self, burst_dmg: f32) -> Self { self.burst_dmg = burst_dmg; self }
+    pub fn plunge_dmg(mut self, plunge_dmg: f32) -> Self { self.plunge_dmg = plunge_dmg; self }
     pub fn all_dmg(mut self, all_dmg: f32) -> Self { self.all_dmg = all_dmg; self }
     pub fn physical_dmg(mut self, physical_dmg: f32) -> Self { self.physical_dmg = physical_dmg; self }
     pub fn elemental_dmg(mut self, elemental_dmg: f32) -> Self { self.elemental_dmg = elemental_dmg; self }
@@ -287,6 +297,8 @@ impl Artifact {
     pub fn dendro_dmg(mut self, dendro_dmg: f32) -> Self { self.dendro_dmg = dendro_dmg; self }
     pub fn amplifying_bonus(mut self, amplifying_bonus: f32) -> Self { self.amplifying_bonus = amplifying_bonus; self }
     pub fn transformative_bonus(mut self, transformative_bonus: f32) -> Self { self.transformative_bonus = transformative_bonus; self }
+    pub fn healing_bonus(mut self, healing_bonus: f32) -> Self { self.healing_bonus = healing_bonus; self }
+    pub fn substats(mut self, substats: SubstatRolls) -> Self { self.substats = substats; self }
 
     pub fn is_physical_goblet_user(&self, name: &str) -> bool {
         match name {
@@ -296,38 +308,330 @@ impl Artifact {
         }
     }
 
-    pub fn infuse_goblet(&mut self, vision: &Vision, name: &str) -> &mut Self {
-        if self.is_physical_goblet_user(name) {
-            self.physical_dmg = 58.3;
-            return self;
-        };
-        match &vision {
-            Vision::Pyro => self.pyro_dmg = 46.6,
-            Vision::Cryo => self.cryo_dmg = 46.6,
-            Vision::Hydro => self.hydro_dmg = 46.6,
-            Vision::Electro => self.electro_dmg = 46.6,
-            Vision::Anemo => self.anemo_dmg = 46.6,
-            Vision::Geo => self.geo_dmg = 46.6,
-            Vision::Dendro => self.dendro_dmg = 46.6,
-            Vision::Physical => self.physical_dmg = 58.3,
-        };
+    // add set bonuses of `other`. Substats are not added.
+    pub fn add_bonus(&mut self, other: &Artifact) -> &mut Self {
+        self.hp += other.hp;
+        self.atk += other.atk;
+        self.def += other.def;
+        self.flat_atk += other.flat_atk;
+        self.cr += other.cr;
+        self.cd += other.cd;
+        self.er += other.er;
+        self.em += other.em;
+        self.atk_spd += other.atk_spd;
+        self.na_dmg += other.na_dmg;
+        self.ca_dmg += other.ca_dmg;
+        self.skill_dmg += other.skill_dmg;
+        self.burst_dmg += other.burst_dmg;
+        self.plunge_dmg += other.plunge_dmg;
+        self.all_dmg += other.all_dmg;
+        self.physical_dmg += other.physical_dmg;
+        self.elemental_dmg += other.elemental_dmg;
+        self.pyro_dmg += other.pyro_dmg;
+        self.cryo_dmg += other.cryo_dmg;
+        self.hydro_dmg += other.hydro_dmg;
+        self.electro_dmg += other.electro_dmg;
+        self.anemo_dmg += other.anemo_dmg;
+        self.geo_dmg += other.geo_dmg;
+        self.dendro_dmg += other.dendro_dmg;
+        self.amplifying_bonus += other.amplifying_bonus;
+        self.transformative_bonus += other.transformative_bonus;
+        self.healing_bonus += other.healing_bonus;
         self
     }
 
-    pub fn dry_goblet(&mut self) -> () {
-        self.pyro_dmg = 0.0;
-        self.cryo_dmg = 0.0;
-        self.hydro_dmg = 0.0;
-        self.electro_dmg = 0.0;
-        self.anemo_dmg = 0.0;
-        self.geo_dmg = 0.0;
-        self.dendro_dmg = 0.0;
-        self.physical_dmg = 0.0;
+    // equip the flower, plume, sands, goblet and circlet. The flower is
+    // considered in `State::HP`. Stats are added to the set bonus, so keep a
+    // copy of the artifact to restore it after the simulation.
+    pub fn equip(&mut self, loadout: &ArtifactLoadout, vision: &Vision, name: &str) -> &mut Self {
+        self.flat_atk += 311.;
+        for main_stat in loadout.iter() {
+            main_stat.apply(self, vision, name, 1.);
+        }
+        let substats = self.substats;
+        substats.apply(self, 1.);
+        self
     }
 }
 
+// main stats of 5 star artifacts at level 20
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub enum MainStat {
+    Hp,
+    Atk,
+    Def,
+    Em,
+    Er,
+    // DMG bonus of the character's vision, or physical DMG bonus if the
+    // character prefers physical attacks (see `is_physical_goblet_user`)
+    DmgBonus,
+    ElementalDmg,
+    PhysicalDmg,
+    Cr,
+    Cd,
+    HealingBonus,
+}
+
+impl MainStat {
+    pub fn name(&self) -> &'static str {
+        match self {
+            MainStat::Hp => "HP%",
+            MainStat::Atk => "ATK%",
+            MainStat::Def => "DEF%",
+            MainStat::Em => "EM",
+            MainStat::Er => "ER",
+            MainStat::DmgBonus => "DMG",
+            MainStat::ElementalDmg => "Elemental DMG",
+            MainStat::PhysicalDmg => "Physical DMG",
+            MainStat::Cr => "CR",
+            MainStat::Cd => "CD",
+            MainStat::HealingBonus => "Healing",
+        }
+    }
 
-#[derive(Debug)]
+    pub fn parse(key: &str) -> Option<Self> {
+        match key {
+            "hp" => Some(MainStat::Hp),
+            "atk" => Some(MainStat::Atk),
+            "def" => Some(MainStat::Def),
+            "em" => Some(MainStat::Em),
+            "er" => Some(MainStat::Er),
+            "dmg" => Some(MainStat::DmgBonus),
+            "elemental" => Some(MainStat::ElementalDmg),
+            "physical" => Some(MainStat::PhysicalDmg),
+            "cr" => Some(MainStat::Cr),
+            "cd" => Some(MainStat::Cd),
+            "healing" => Some(MainStat::HealingBonus),
+            _ => None,
+        }
+    }
+
+    pub fn is_sands(&self) -> bool {
+        match self {
+            MainStat::Hp | MainStat::Atk | MainStat::Def | MainStat::Em | MainStat::Er => true,
+            _ => false,
+        }
+    }
+
+    pub fn is_goblet(&self) -> bool {
+        match self {
+            MainStat::Hp | MainStat::Atk | MainStat::Def | MainStat::Em |
+            MainStat::DmgBonus | MainStat::ElementalDmg | MainStat::PhysicalDmg => true,
+            _ => false,
+        }
+    }
+
+    pub fn is_circlet(&self) -> bool {
+        match self {
+            MainStat::Hp | MainStat::Atk | MainStat::Def | MainStat::Em |
+            MainStat::Cr | MainStat::Cd | MainStat::HealingBonus => true,
+            _ => false,
+        }
+    }
+
+    fn apply(&self, ar: &mut Artifact, vision: &Vision, name: &str, sign: f32) -> () {
+        match self {
+            MainStat::Hp => ar.hp += sign * 46.6,
+            MainStat::Atk => ar.atk += sign * 46.6,
+            MainStat::Def => ar.def += sign * 58.3,
+            MainStat::Em => ar.em += sign * 186.5,
+            MainStat::Er => ar.er += sign * 51.8,
+            MainStat::DmgBonus => if ar.is_physical_goblet_user(name) {
+                ar.physical_dmg += sign * 58.3;
+            } else {
+                MainStat::ElementalDmg.apply(ar, vision, name, sign);
+            },
+            MainStat::ElementalDmg => match &vision {
+                Vision::Pyro => ar.pyro_dmg += sign * 46.6,
+                Vision::Cryo => ar.cryo_dmg += sign * 46.6,
+                Vision::Hydro => ar.hydro_dmg += sign * 46.6,
+                Vision::Electro => ar.electro_dmg += sign * 46.6,
+                Vision::Anemo => ar.anemo_dmg += sign * 46.6,
+                Vision::Geo => ar.geo_dmg += sign * 46.6,
+                Vision::Dendro => ar.dendro_dmg += sign * 46.6,
+                Vision::Physical => ar.physical_dmg += sign * 58.3,
+            },
+            MainStat::PhysicalDmg => ar.physical_dmg += sign * 58.3,
+            MainStat::Cr => ar.cr += sign * 31.1,
+            MainStat::Cd => ar.cd += sign * 62.2,
+            MainStat::HealingBonus => ar.healing_bonus += sign * 35.9,
+        }
+    }
+}
+
+// Main stats of sands, goblet and circlet. `None` means the artifact has
+// substats only. The default loadout is an elemental (or physical) goblet.
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct ArtifactLoadout {
+    pub sands: Option<MainStat>,
+    pub goblet: Option<MainStat>,
+    pub circlet: Option<MainStat>,
+}
+
+impl Default for ArtifactLoadout {
+    fn default() -> Self {
+        Self {
+            sands: None,
+            goblet: Some(MainStat::DmgBonus),
+            circlet: None,
+        }
+    }
+}
+
+impl ArtifactLoadout {
+    pub fn new(sands: Option<MainStat>, goblet: Option<MainStat>, circlet: Option<MainStat>) -> Self {
+        Self {
+            sands,
+            goblet,
+            circlet,
+        }
+    }
+
+    // all combinations of the given main stats
+    pub fn all(sands: &[Option<MainStat>], goblet: &[Option<MainStat>], circlet: &[Option<MainStat>]) -> Vec<Self> {
+        let mut result: Vec<Self> = Vec::with_capacity(sands.len() * goblet.len() * circlet.len());
+        for s in sands.iter() {
+            for g in goblet.iter() {
+                for c in circlet.iter() {
+                    result.push(Self::new(*s, *g, *c));
+                }
+            }
+        }
+        result
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &MainStat> {
+        self.sands.iter().chain(self.goblet.iter()).chain(self.circlet.iter())
+    }
+
+    pub fn head(&self) -> [&'static str; 3] {
+        let name = |x: &Option<MainStat>| if let Some(m) = x { m.name() } else { "-" };
+        [name(&self.sands), name(&self.goblet), name(&self.circlet)]
+    }
+}
+
+// the number of substat rolls of five artifacts. 36 rolls are roughly 140
+// points of crit value.
+pub const SUBSTAT_ROLLS: f32 = 36.0;
+
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
+pub enum Substat {
+    Hp,
+    Atk,
+    Def,
+    Em,
+    Er,
+    Cr,
+    Cd,
+}
+
+impl Substat {
+    pub const ALL: [Substat; 7] = [Substat::Hp, Substat::Atk, Substat::Def, Substat::Em, Substat::Er, Substat::Cr, Substat::Cd];
+
+    // the maximum roll of 5 star artifacts
+    pub fn roll_value(&self) -> f32 {
+        match self {
+            Substat::Hp => 5.83,
+            Substat::Atk => 5.83,
+            Substat::Def => 7.29,
+            Substat::Em => 23.31,
+            Substat::Er => 6.48,
+            Substat::Cr => 3.89,
+            Substat::Cd => 7.77,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Substat::Hp => "HP%",
+            Substat::Atk => "ATK%",
+            Substat::Def => "DEF%",
+            Substat::Em => "EM",
+            Substat::Er => "ER",
+            Substat::Cr => "CR",
+            Substat::Cd => "CD",
+        }
+    }
+    pub fn is_main_stat(&self, main_stat: &MainStat) -> bool {
+        match (self, main_stat) {
+            (Substat::Hp, MainStat::Hp) |
+            (Substat::Atk, MainStat::Atk) |
+            (Substat::Def, MainStat::Def) |
+            (Substat::Em, MainStat::Em) |
+            (Substat::Er, MainStat::Er) |
+            (Substat::Cr, MainStat::Cr) |
+            (Substat::Cd, MainStat::Cd) => true,
+            _ => false,
+        }
+    }
+}
+
+// substats in roll counts
+#[derive(Debug, PartialEq, Copy, Clone, Default)]
+pub struct SubstatRolls {
+    pub hp: f32,
+    pub atk: f32,
+    pub def: f32,
+    pub em: f32,
+    pub er: f32,
+    pub cr: f32,
+    pub cd: f32,
+}
+
+impl SubstatRolls {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn hp(mut self, hp: f32) -> Self { self.hp = hp; self }
+    pub fn atk(mut self, atk: f32) -> Self { self.atk = atk; self }
+    pub fn def(mut self, def: f32) -> Self { self.def = def; self }
+    pub fn em(mut self, em: f32) -> Self { self.em = em; self }
+    pub fn er(mut self, er: f32) -> Self { self.er = er; self }
+    pub fn cr(mut self, cr: f32) -> Self { self.cr = cr; self }
+    pub fn cd(mut self, cd: f32) -> Self { self.cd = cd; self }
+
+    pub fn get(&self, substat: &Substat) -> f32 {
+        match substat {
+            Substat::Hp => self.hp,
+            Substat::Atk => self.atk,
+            Substat::Def => self.def,
+            Substat::Em => self.em,
+            Substat::Er => self.er,
+            Substat::Cr => self.cr,
+            Substat::Cd => self.cd,
+        }
+    }
+
+    pub fn add(&mut self, substat: &Substat, rolls: f32) -> &mut Self {
+        match substat {
+            Substat::Hp => self.hp += rolls,
+            Substat::Atk => self.atk += rolls,
+            Substat::Def => self.def += rolls,
+            Substat::Em => self.em += rolls,
+            Substat::Er => self.er += rolls,
+            Substat::Cr => self.cr += rolls,
+            Substat::Cd => self.cd += rolls,
+        };
+        self
+    }
+
+    pub fn total(&self) -> f32 {
+        Substat::ALL.iter().map(|s| self.get(s)).sum()
+    }
+
+    fn apply(&self, ar: &mut Artifact, sign: f32) -> () {
+        ar.hp += sign * self.hp * Substat::Hp.roll_value();
+        ar.atk += sign * self.atk * Substat::Atk.roll_value();
+        ar.def += sign * self.def * Substat::Def.roll_value();
+        ar.em += sign * self.em * Substat::Em.roll_value();
+        ar.er += sign * self.er * Substat::Er.roll_value();
+        ar.cr += sign * self.cr * Substat::Cr.roll_value();
+        ar.cd += sign * self.cd * Substat::Cd.roll_value();
+    }
+}
+
+#[derive(Debug, Clone)]
 pub struct Resistance {
     pub pyro: f32,
     pub hydro: f32,
@@ -365,6 +669,105 @@ impl Resistance {
             physical: 0.0,
         }
     }
+
+    pub fn all(value: f32) -> Self {
+        Self {
+            pyro: value,
+            hydro: value,
+            electro: value,
+            cryo: value,
+            anemo: value,
+            geo: value,
+            dendro: value,
+            physical: value,
+        }
+    }
+
+    pub fn get_mut(&mut self, element: &Vision) -> &mut f32 {
+        match element {
+            Vision::Pyro => &mut self.pyro,
+            Vision::Hydro => &mut self.hydro,
+            Vision::Electro => &mut self.electro,
+            Vision::Cryo => &mut self.cryo,
+            Vision::Anemo => &mut self.anemo,
+            Vision::Geo => &mut self.geo,
+            Vision::Dendro => &mut self.dendro,
+            Vision::Physical => &mut self.physical,
+        }
+    }
+}
+
+// an enemy that appears after the current one is killed
+#[derive(Debug, Clone)]
+pub struct Wave {
+    pub hp: f32,
+    pub level: f32,
+    pub resistance: Resistance,
+}
+
+impl Wave {
+    pub fn new(hp: f32) -> Self {
+        Self {
+            hp,
+            level: 90.0,
+            resistance: Resistance::normal(),
+        }
+    }
+
+    pub fn level(mut self, level: f32) -> Self { self.level = level; self }
+    pub fn resistance(mut self, resistance: Resistance) -> Self { self.resistance = resistance; self }
+}
+
+// a stat of an enemy which is reduced by a debuff
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum DebuffStat {
+    Res(Vision),
+    Def,
+}
+
+// how applications of a debuff by the same source overlap
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Stacking {
+    // a new application replaces the old one and refreshes the duration
+    Refresh,
+    // applications are summed up
+    Stack,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Debuff {
+    pub source: &'static str,
+    pub stat: DebuffStat,
+    // percentage of RES or DEF
+    pub value: f32,
+    pub start: f32,
+    pub duration: f32,
+    pub stacking: Stacking,
+}
+
+impl Debuff {
+    pub fn new(source: &'static str, stat: DebuffStat, value: f32, start: f32, duration: f32) -> Self {
+        Self {
+            source,
+            stat,
+            value,
+            start,
+            duration,
+            stacking: Stacking::Refresh,
+        }
+    }
+
+    pub fn stacking(mut self, stacking: Stacking) -> Self { self.stacking = stacking; self }
+
+    pub fn end(&self) -> f32 {
+        self.start + self.duration
+    }
+
+    // the end is excluded, so that a refreshed debuff does not overlap the
+    // next application
+    pub fn is_active(&self, time: f32) -> bool {
+        self.start <= time && time < self.end()
+    }
 }
 
 #[derive(Debug)]
@@ -376,11 +779,21 @@ pub struct Enemy {
     pub aura: ElementalGauge,
     pub isfrozen: bool,
 
-    pub debuff: Resistance,
-    pub def_down: f32,
-    // indicates the time when superconduct is applied
-    // superconduct is not applied if `superconduct_time` is a large negative value
-    pub superconduct_time: f32,
+    // debuffs in the order of application. expired ones are removed when a
+    // new one is applied.
+    pub debuffs: Vec<Debuff>,
+    // the number of applications, which tells tracers that debuffs changed
+    pub applied_debuffs: usize,
+
+    // HP of the current enemy. enemies have infinite HP unless `hp` or
+    // `waves` is given.
+    pub max_hp: f32,
+    pub hp: f32,
+    // enemies that appear after the current one is killed, in the reverse
+    // order
+    pub waves: Vec<Wave>,
+    // the times when enemies were killed
+    pub kills: Vec<f32>,
 }
 
 impl Enemy {
@@ -391,9 +804,12 @@ impl Enemy {
             aura_time: 0.0,
             aura: ElementalGauge::default(),
             isfrozen: false,
-            debuff: Resistance::zero(),
-            def_down: 0.0,
-            superconduct_time: -99.0,
+            debuffs: Vec::new(),
+            applied_debuffs: 0,
+            max_hp: f32::INFINITY,
+            hp: f32::INFINITY,
+            waves: Vec::new(),
+            kills: Vec::new(),
         }
     }
 
@@ -404,9 +820,101 @@ impl Enemy {
             aura_time: 0.0,
             aura: ElementalGauge::default(),
             isfrozen: false,
-            debuff: Resistance::zero(),
-            def_down: 0.0,
-            superconduct_time: -99.0,
+            debuffs: Vec::new(),
+            applied_debuffs: 0,
+            max_hp: f32::INFINITY,
+            hp: f32::INFINITY,
+            waves: Vec::new(),
+            kills: Vec::new(),
+        }
+    }
+
+    pub fn hp(mut self, hp: f32) -> Self { self.max_hp = hp; self.hp = hp; self }
+
+    // an aura applied before the simulation, e.g. by the previous rotation.
+    // the decay rate follows the gauge of attacks with the same unit.
+    pub fn aura(mut self, element: Vision, unit: f32) -> Self {
+        let decay = if unit <= 1. {
+            ElementalGaugeDecay::A
+        } else if unit <= 2. {
+            ElementalGaugeDecay::B
+        } else {
+            ElementalGaugeDecay::C
+        };
+        self.aura = ElementalGauge::new(element, unit, decay);
+        self.aura_time = 0.;
+        self
+    }
+
+    // enemies are fought one after another in the order of `hp`
+    pub fn waves(mut self, hp: &[f32]) -> Self {
+        if let Some((first, rest)) = hp.split_first() {
+            self.max_hp = *first;
+            self.hp = *first;
+            self.waves = rest.iter().rev().map(|hp| Wave::new(*hp).level(self.level).resistance(self.default.clone())).collect();
+        }
+        self
+    }
+
+    // enemies of different levels and resistances fought one after another
+    pub fn spawn(waves: &[Wave]) -> Self {
+        let mut enemy = Self::hilichurl();
+        if let Some((first, rest)) = waves.split_first() {
+            enemy.level = first.level;
+            enemy.default = first.resistance.clone();
+            enemy.max_hp = first.hp;
+            enemy.hp = first.hp;
+            enemy.waves = rest.iter().rev().cloned().collect();
+        }
+        enemy
+    }
+
+    // HP of the current and the following enemies
+    pub fn hp_left(&self) -> f32 {
+        self.hp.max(0.) + self.waves.iter().map(|w| w.hp).sum::<f32>()
+    }
+
+    // 1 if HP is infinite
+    pub fn hp_ratio(&self) -> f32 {
+        if self.max_hp.is_finite() {
+            self.hp / self.max_hp
+        } else {
+            1.
+        }
+    }
+
+    // all enemies were killed
+    pub fn is_cleared(&self) -> bool {
+        self.hp <= 0.
+    }
+
+    pub fn clear_time(&self) -> Option<f32> {
+        if self.is_cleared() {
+            self.kills.last().cloned()
+        } else {
+            None
+        }
+    }
+
+    // Excess damage does not carry over to the next enemy, which appears
+    // without auras and debuffs.
+    pub fn take_damage(&mut self, time: f32, damage: f32) -> () {
+        if self.is_cleared() {
+            return;
+        }
+        self.hp -= damage;
+        if self.hp <= 0. {
+            self.kills.push(time);
+            if let Some(wave) = self.waves.pop() {
+                self.level = wave.level;
+                self.default = wave.resistance;
+                self.max_hp = wave.hp;
+                self.hp = wave.hp;
+                self.aura = ElementalGauge::default();
+                self.aura_time = time;
+                self.isfrozen = false;
+                self.debuffs.clear();
+            }
         }
     }
 
@@ -429,52 +937,50 @@ impl Enemy {
         self.aura.trigger2(attack.time, &mut self.aura_time, &attack.element);
         match &elemental_reaction {
             Freeze(_) => self.isfrozen = true,
-            Superconduct(_) => self.superconduct_time = attack.time,
+            Superconduct(_) => self.apply_debuff(attack.time, Debuff::new("Superconduct", DebuffStat::Res(Vision::Physical), 40., attack.time, 12.)),
             _ => (),
         }
     }
 
-    pub fn resistance(&self, current_time: f32, element: &Vision) -> f32 {
-        let resistance: f32;
-        let debuff: f32;
-        match element {
-            Vision::Pyro => {
-                resistance = self.default.pyro;
-                debuff = self.debuff.pyro;
-            },
-            Vision::Hydro => {
-                resistance = self.default.hydro;
-                debuff = self.debuff.hydro;
-            },
-            Vision::Electro => {
-                resistance = self.default.electro;
-                debuff = self.debuff.electro;
-            },
-            Vision::Cryo => {
-                resistance = self.default.cryo;
-                debuff = self.debuff.cryo;
-            },
-            Vision::Anemo => {
-                resistance = self.default.anemo;
-                debuff = self.debuff.anemo;
-            },
-            Vision::Geo => {
-                resistance = self.default.geo;
-                debuff = self.debuff.geo;
-            },
-            Vision::Dendro => {
-                resistance = self.default.dendro;
-                debuff = self.debuff.dendro;
-            },
-            Vision::Physical => {
-                resistance = self.default.physical;
-                debuff = self.debuff.physical + if current_time - self.superconduct_time <= 12.0 {
-                    40.0
-                } else {
-                    0.0
-                };
-            },
+    // `debuff` may start later than `current_time`, e.g. when a field ends
+    pub fn apply_debuff(&mut self, current_time: f32, debuff: Debuff) -> () {
+        self.debuffs.retain(|d| current_time <= d.end());
+        if debuff.stacking == Stacking::Refresh {
+            // the new application replaces the old one from its start, and
+            // the old one stays until then, e.g. Chongyun's field of the next
+            // skill starts after the current field ends.
+            for d in self.debuffs.iter_mut() {
+                if d.source == debuff.source && d.stat == debuff.stat && d.start < debuff.start {
+                    d.duration = d.duration.min(debuff.start - d.start);
+                }
+            }
+            self.debuffs.retain(|d| d.source != debuff.source || d.stat != debuff.stat || d.start < debuff.start);
         }
+        self.debuffs.push(debuff);
+        self.applied_debuffs += 1;
+    }
+
+    // the sum of active debuffs of `stat`
+    pub fn debuff(&self, current_time: f32, stat: DebuffStat) -> f32 {
+        self.debuffs.iter().filter(|d| d.stat == stat && d.is_active(current_time)).map(|d| d.value).sum()
+    }
+
+    pub fn def_down(&self, current_time: f32) -> f32 {
+        self.debuff(current_time, DebuffStat::Def)
+    }
+
+    pub fn resistance(&self, current_time: f32, element: &Vision) -> f32 {
+        let resistance = match element {
+            Vision::Pyro => self.default.pyro,
+            Vision::Hydro => self.default.hydro,
+            Vision::Electro => self.default.electro,
+            Vision::Cryo => self.default.cryo,
+            Vision::Anemo => self.default.anemo,
+            Vision::Geo => self.default.geo,
+            Vision::Dendro => self.default.dendro,
+            Vision::Physical => self.default.physical,
+        };
+        let debuff = self.debuff(current_time, DebuffStat::Res(*element));
         let res = if debuff > resistance {
             -0.5 * (debuff - resistance)
         } else {
@@ -521,7 +1027,10 @@ impl<'a> CharacterData<'a> {
             CharacterAction::Burst |
             CharacterAction::PressSkill |
             CharacterAction::HoldSkill |
-            CharacterAction::Ca(_) => self.na_idx = 1,
+            CharacterAction::Ca(_) |
+            CharacterAction::Plunge |
+            CharacterAction::Dash |
+            CharacterAction::Jump => self.na_idx = 1,
             _ => (),
         };
     }
@@ -0,0 +1,216 @@
+use std::fmt::Write;
+
+use crate::sim2::state::State;
+use crate::sim2::timeline::ActionState;
+use crate::sim2::attack::{Attack, DamageResult, DamageResultUtil};
+use crate::sim2::types::CharacterAction;
+use crate::sim2::element::ElementalReactionType;
+use crate::sim2::record::{CharacterData, Enemy};
+use crate::sim2::party::{Party, PartyError, SimulationConfig};
+use crate::sim2::rotation::Rotation;
+use crate::sim2::trace::Tracer;
+
+// collects a rotation and reactions for a report
+struct ReportTracer {
+    rotation: Rotation,
+    reactions: Vec<(&'static str, usize)>,
+}
+
+impl Tracer for ReportTracer {
+    fn action(&mut self, data: &CharacterData, action: &CharacterAction, state: &ActionState) -> () {
+        self.rotation.action(data, action, state);
+    }
+
+    fn modify(&mut self, source: &'static str, attack: &Attack, before: &State, after: &State) -> () {
+        self.rotation.modify(source, attack, before, after);
+    }
+
+    fn reaction(&mut self, _attack: &Attack, reaction: &ElementalReactionType) -> () {
+        let name = reaction.name();
+        match self.reactions.iter_mut().find(|(n, _)| *n == name) {
+            Some((_, count)) => *count += 1,
+            None => self.reactions.push((name, 1)),
+        }
+    }
+}
+
+// the result of a run of a party
+pub struct RunRecord {
+    pub title: String,
+    pub simulation_time: f32,
+    pub damage: Vec<DamageResult>,
+    pub rotation: Rotation,
+    pub reactions: Vec<(&'static str, usize)>,
+}
+
+impl RunRecord {
+    pub fn run(title: &str, party: &mut Party, config: &SimulationConfig, enemy: &mut Enemy) -> Result<Self, PartyError> {
+        let mut tracer = ReportTracer {
+            rotation: Rotation::new(config.unit_time),
+            reactions: Vec::new(),
+        };
+        let damage = party.run_with(config, enemy, &mut tracer)?;
+        Ok(Self {
+            title: title.to_string(),
+            simulation_time: config.simulation_time,
+            damage,
+            rotation: tracer.rotation,
+            reactions: tracer.reactions,
+        })
+    }
+
+    pub fn total_damage(&self) -> f32 {
+        self.damage.total_damage()
+    }
+
+    pub fn dps(&self) -> f32 {
+        self.total_damage() / self.simulation_time
+    }
+
+    pub fn per_second(&self) -> Vec<f32> {
+        let mut result = vec![0.; self.simulation_time.ceil().max(1.) as usize];
+        for d in self.damage.iter() {
+            let i = (d.time.floor() as usize).min(result.len() - 1);
+            result[i] += d.total_damage();
+        }
+        result
+    }
+
+    // total damage by member, then by type of damage in the order of appearance
+    pub fn breakdown(&self) -> Vec<(&'static str, Vec<(String, f32)>)> {
+        let mut result: Vec<(&'static str, Vec<(String, f32)>)> = Vec::new();
+        for d in self.damage.iter() {
+            let i = match result.iter().position(|(n, _)| *n == d.name) {
+                Some(i) => i,
+                None => {
+                    result.push((d.name, Vec::new()));
+                    result.len() - 1
+                },
+            };
+            let kinds = &mut result[i].1;
+            let kind = format!("{:?}", d.kind);
+            match kinds.iter_mut().find(|(k, _)| *k == kind) {
+                Some((_, v)) => *v += d.damage,
+                None => kinds.push((kind, d.damage)),
+            }
+            if d.reaction > 0. {
+                match kinds.iter_mut().find(|(k, _)| k == "Reaction") {
+                    Some((_, v)) => *v += d.reaction,
+                    None => kinds.push(("Reaction".to_string(), d.reaction)),
+                }
+            }
+        }
+        result
+    }
+}
+
+fn escape(s: &str) -> String {
+    s.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;")
+}
+
+// bars of damage per second and a line of the average DPS so far
+fn dps_chart(run: &RunRecord) -> String {
+    let values = run.per_second();
+    let (width, height, left, bottom) = (640., 200., 50., 20.);
+    let max = values.iter().cloned().fold(1., f32::max);
+    let bar = (width - left) / values.len() as f32;
+    let y = |v: f32| height - bottom - (height - bottom - 10.) * v / max;
+    let mut svg = String::new();
+    writeln!(svg, r#"<svg xmlns="http://www.w3.org/2000/svg" width="{}" height="{}" font-size="10">"#, width, height).unwrap();
+    let mut total = 0.;
+    let mut points: Vec<String> = Vec::with_capacity(values.len());
+    for (i, v) in values.iter().enumerate() {
+        let x = left + i as f32 * bar;
+        writeln!(svg, r##"<rect x="{:.1}" y="{:.1}" width="{:.1}" height="{:.1}" fill="#e9c46a"><title>{}s: {:.0}</title></rect>"##,
+            x + 1., y(*v), bar - 2., height - bottom - y(*v), i, v).unwrap();
+        total += v;
+        points.push(format!("{:.1},{:.1}", x + bar / 2., y(total / (i + 1) as f32)));
+        if i % 5 == 0 {
+            writeln!(svg, r#"<text x="{:.1}" y="{:.1}">{}s</text>"#, x, height - 5., i).unwrap();
+        }
+    }
+    writeln!(svg, r##"<polyline points="{}" fill="none" stroke="#e76f51" stroke-width="2"/>"##, points.join(" ")).unwrap();
+    writeln!(svg, r#"<text x="0" y="15">{:.0}</text><text x="0" y="{:.1}">0</text>"#, max, height - bottom).unwrap();
+    writeln!(svg, "</svg>").unwrap();
+    svg
+}
+
+fn run_section(html: &mut String, i: usize, run: &RunRecord) -> () {
+    let total = run.total_damage();
+    writeln!(html, r#"<section id="run{}"><h2>{}. {}</h2>"#, i, i, escape(&run.title)).unwrap();
+    writeln!(html, "<p>total damage <b>{:.0}</b>, DPS <b>{:.0}</b></p>", total, run.dps()).unwrap();
+    writeln!(html, "<h3>Damage per second</h3><p>bars: damage in each second, line: average DPS so far</p>{}", dps_chart(run)).unwrap();
+    writeln!(html, "<h3>Breakdown</h3><table><tr><th>member</th><th>source</th><th>damage</th><th>share</th></tr>").unwrap();
+    for (name, kinds) in run.breakdown().iter() {
+        let member: f32 = kinds.iter().map(|(_, v)| v).sum();
+        writeln!(html, r#"<tr class="member"><td>{}</td><td>all</td><td>{:.0}</td><td>{:.1}%</td></tr>"#, escape(name), member, 100. * member / total.max(1.)).unwrap();
+        for (kind, v) in kinds.iter() {
+            writeln!(html, "<tr><td></td><td>{}</td><td>{:.0}</td><td>{:.1}%</td></tr>", kind, v, 100. * v / total.max(1.)).unwrap();
+        }
+    }
+    writeln!(html, "</table>").unwrap();
+    writeln!(html, "<h3>Reactions</h3>").unwrap();
+    if run.reactions.is_empty() {
+        writeln!(html, "<p>none</p>").unwrap();
+    } else {
+        writeln!(html, "<table><tr><th>reaction</th><th>count</th></tr>").unwrap();
+        for (name, count) in run.reactions.iter() {
+            writeln!(html, "<tr><td>{}</td><td>{}</td></tr>", name, count).unwrap();
+        }
+        writeln!(html, "</table>").unwrap();
+    }
+    writeln!(html, "<h3>Rotation and energy</h3>{}</section>", run.rotation.to_svg()).unwrap();
+}
+
+// A self-contained HTML page of runs, which can be opened without network.
+pub fn html(title: &str, runs: &[RunRecord]) -> String {
+    let mut html = String::new();
+    writeln!(html, r#"<!DOCTYPE html>
+<html><head><meta charset="utf-8"><title>{}</title>
+<style>
+body {{ font-family: sans-serif; margin: 2em; }}
+table {{ border-collapse: collapse; margin-bottom: 1em; }}
+th, td {{ border: 1px solid #ccc; padding: 2px 8px; text-align: right; }}
+th:first-child, td:first-child, td:nth-child(2) {{ text-align: left; }}
+tr.member {{ font-weight: bold; background: #f4f4f4; }}
+section {{ border-top: 2px solid #333; margin-top: 2em; }}
+</style></head><body>
+<h1>{}</h1>"#, escape(title), escape(title)).unwrap();
+    writeln!(html, "<table><tr><th>#</th><th>team</th><th>total damage</th><th>DPS</th></tr>").unwrap();
+    for (i, run) in runs.iter().enumerate() {
+        writeln!(html, r##"<tr><td>{}</td><td><a href="#run{}">{}</a></td><td>{:.0}</td><td>{:.0}</td></tr>"##,
+            i + 1, i + 1, escape(&run.title), run.total_damage(), run.dps()).unwrap();
+    }
+    writeln!(html, "</table>").unwrap();
+    for (i, run) in runs.iter().enumerate() {
+        run_section(&mut html, i + 1, run);
+    }
+    writeln!(html, "</body></html>").unwrap();
+    html
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sim2::party::PartyMember;
+
+    #[test]
+    fn report() {
+        let mut config = SimulationConfig::default();
+        config.simulation_time = 6.0;
+        let mut party = Party::new()
+            .member(PartyMember::find("Xiangling", "The Catch", "Emblem of Severed Fate").unwrap())
+            .member(PartyMember::find("Xingqiu", "Sacrificial Sword", "Noblesse Oblige").unwrap());
+        let run = RunRecord::run("Xiangling Xingqiu", &mut party, &config, &mut Enemy::hilichurl()).unwrap();
+        let breakdown = run.breakdown();
+        let sum: f32 = breakdown.iter().flat_map(|(_, kinds)| kinds.iter().map(|(_, v)| v)).sum();
+        assert!((sum - run.total_damage()).abs() < 1.);
+        assert_eq!(run.per_second().len(), 6);
+        // Pyro and Hydro
+        assert!(run.reactions.iter().any(|(n, _)| *n == "Vaporize"));
+        let html = html("report", &[run]);
+        assert!(html.contains("Vaporize"));
+        // no external assets
+        assert!(!html.contains("src=") && !html.contains("href=\"http"));
+    }
+}
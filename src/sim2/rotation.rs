@@ -0,0 +1,268 @@
+use std::fmt::Write;
+
+use crate::sim2::state::State;
+use crate::sim2::timeline::ActionState;
+use crate::sim2::attack::Attack;
+use crate::sim2::types::CharacterAction;
+use crate::sim2::record::CharacterData;
+use crate::sim2::trace::Tracer;
+
+// actions and energy of a member at each tick
+#[derive(Debug)]
+pub struct Lane {
+    pub name: &'static str,
+    pub energy_cost: f32,
+    pub actions: Vec<(f32, CharacterAction)>,
+    pub energy: Vec<(f32, f32)>,
+}
+
+// a period of time when `modify` of `source` changed stats
+#[derive(Debug, PartialEq)]
+pub struct BuffWindow {
+    pub source: &'static str,
+    pub start: f32,
+    pub end: f32,
+}
+
+// A rotation collected from a simulation, which can be drawn as an ASCII Gantt
+// chart or an SVG image.
+#[derive(Debug)]
+pub struct Rotation {
+    pub unit_time: f32,
+    pub lanes: Vec<Lane>,
+    pub buffs: Vec<BuffWindow>,
+}
+
+fn symbol(action: &CharacterAction) -> char {
+    use CharacterAction::*;
+    match action {
+        PressSkill => 'E',
+        HoldSkill => 'H',
+        Burst => 'Q',
+        Ca(_) => 'C',
+        Plunge => 'P',
+        Dash => 'd',
+        Jump => 'j',
+        StandStill => '.',
+        _ => 'n',
+    }
+}
+
+fn color(action: &CharacterAction) -> &'static str {
+    use CharacterAction::*;
+    match action {
+        PressSkill | HoldSkill => "#2a9d8f",
+        Burst => "#e76f51",
+        Ca(_) => "#8d5ee8",
+        Plunge => "#264653",
+        Dash | Jump => "#bbbbbb",
+        _ => "#e9c46a",
+    }
+}
+
+impl Rotation {
+    pub fn new(unit_time: f32) -> Self {
+        Self {
+            unit_time,
+            lanes: Vec::new(),
+            buffs: Vec::new(),
+        }
+    }
+
+    pub fn end_time(&self) -> f32 {
+        self.lanes.iter().flat_map(|l| l.actions.last()).map(|(t, _)| *t + self.unit_time).fold(0., f32::max)
+    }
+
+    fn n_ticks(&self) -> usize {
+        (self.end_time() / self.unit_time).round() as usize
+    }
+
+    fn tick(&self, time: f32) -> usize {
+        (time / self.unit_time).round() as usize
+    }
+
+    // One column is a tick. E: press skill, H: hold skill, Q: burst, n: NA,
+    // C: CA, P: plunge, d: dash, j: jump. Energy is drawn from 0 to 9 as a
+    // ratio of the energy cost, and `*` means the burst is ready.
+    pub fn to_ascii(&self) -> String {
+        let n = self.n_ticks();
+        let label = self.lanes.iter().map(|l| l.name.len()).chain(self.buffs.iter().map(|b| b.source.len() + 2)).max().unwrap_or(0).max(6);
+        let mut result = String::new();
+        let mut ruler = String::with_capacity(n);
+        for i in 0..n {
+            let t = i as f32 * self.unit_time;
+            let s = (t - t.round()).abs() < 1e-3 && t.round() as usize % 5 == 0;
+            ruler.push(if s { '|' } else if (t - t.round()).abs() < 1e-3 { '\'' } else { ' ' });
+        }
+        writeln!(result, "{:<w$} {}", "time", ruler, w = label).unwrap();
+        let mut labels = String::new();
+        let mut i = 0;
+        while i < n {
+            let t = i as f32 * self.unit_time;
+            if (t - t.round()).abs() < 1e-3 && t.round() as usize % 5 == 0 {
+                let s = format!("{}s", t.round());
+                i += s.len();
+                labels.push_str(&s);
+            } else {
+                labels.push(' ');
+                i += 1;
+            }
+        }
+        writeln!(result, "{:<w$} {}", "", labels, w = label).unwrap();
+        for lane in self.lanes.iter() {
+            let mut actions: Vec<char> = vec![' '; n];
+            for (t, a) in lane.actions.iter() {
+                let i = self.tick(*t);
+                if i < n {
+                    actions[i] = symbol(a);
+                }
+            }
+            let mut energy: Vec<char> = vec![' '; n];
+            for (t, e) in lane.energy.iter() {
+                let i = self.tick(*t);
+                if i < n {
+                    energy[i] = if *e >= lane.energy_cost {
+                        '*'
+                    } else {
+                        std::char::from_digit((10. * e / lane.energy_cost) as u32, 10).unwrap_or('9')
+                    };
+                }
+            }
+            writeln!(result, "{:<w$} {}", lane.name, actions.iter().collect::<String>(), w = label).unwrap();
+            writeln!(result, "{:<w$} {}", "energy", energy.iter().collect::<String>(), w = label).unwrap();
+        }
+        for b in self.buffs.iter() {
+            let mut line: Vec<char> = vec![' '; n];
+            for i in self.tick(b.start)..self.tick(b.end).min(n) {
+                line[i] = '=';
+            }
+            writeln!(result, "{:<w$} {}", format!("+ {}", b.source), line.iter().collect::<String>(), w = label).unwrap();
+        }
+        result
+    }
+
+    pub fn to_svg(&self) -> String {
+        let scale = 40.;
+        let left = 160.;
+        let lane_height = 50.;
+        let buff_height = 16.;
+        let end_time = self.end_time();
+        let width = left + end_time * scale + 20.;
+        let axis = 30.;
+        let height = axis + self.lanes.len() as f32 * lane_height + self.buffs.len() as f32 * buff_height + 10.;
+        let mut svg = String::new();
+        writeln!(svg, r#"<svg xmlns="http://www.w3.org/2000/svg" width="{:.0}" height="{:.0}" font-family="sans-serif" font-size="12">"#, width, height).unwrap();
+        writeln!(svg, r#"<rect width="100%" height="100%" fill="white"/>"#).unwrap();
+        // time axis
+        let mut t = 0.;
+        while t <= end_time {
+            let x = left + t * scale;
+            writeln!(svg, r##"<line x1="{:.1}" y1="{:.1}" x2="{:.1}" y2="{:.1}" stroke="#dddddd"/>"##, x, axis - 5., x, height - 10.).unwrap();
+            writeln!(svg, r#"<text x="{:.1}" y="{:.1}" text-anchor="middle">{}s</text>"#, x, axis - 10., t).unwrap();
+            t += 1.;
+        }
+        for (li, lane) in self.lanes.iter().enumerate() {
+            let y = axis + li as f32 * lane_height;
+            writeln!(svg, r#"<text x="5" y="{:.1}">{}</text>"#, y + 18., escape(lane.name)).unwrap();
+            for (t, a) in lane.actions.iter() {
+                if *a == CharacterAction::StandStill {
+                    continue;
+                }
+                writeln!(svg, r#"<rect x="{:.1}" y="{:.1}" width="{:.1}" height="20" fill="{}"><title>{:.1}s {:?}</title></rect>"#,
+                    left + t * scale, y + 4., self.unit_time * scale, color(a), t, a).unwrap();
+            }
+            // energy from 0 to the energy cost
+            let points: Vec<String> = lane.energy.iter()
+                .map(|(t, e)| format!("{:.1},{:.1}", left + t * scale, y + 44. - 16. * (e / lane.energy_cost).min(1.)))
+                .collect();
+            writeln!(svg, r##"<polyline points="{}" fill="none" stroke="#457b9d"/>"##, points.join(" ")).unwrap();
+        }
+        for (bi, b) in self.buffs.iter().enumerate() {
+            let y = axis + self.lanes.len() as f32 * lane_height + bi as f32 * buff_height;
+            writeln!(svg, r#"<text x="5" y="{:.1}" font-size="10">+ {}</text>"#, y + 11., escape(b.source)).unwrap();
+            writeln!(svg, r##"<rect x="{:.1}" y="{:.1}" width="{:.1}" height="10" fill="#a8dadc"/>"##,
+                left + b.start * scale, y + 2., (b.end - b.start) * scale).unwrap();
+        }
+        writeln!(svg, "</svg>").unwrap();
+        svg
+    }
+}
+
+fn escape(s: &str) -> String {
+    s.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('\'', "&apos;")
+}
+
+impl Tracer for Rotation {
+    fn action(&mut self, data: &CharacterData, action: &CharacterAction, state: &ActionState) -> () {
+        let idx = data.idx.0;
+        while self.lanes.len() <= idx {
+            self.lanes.push(Lane {
+                name: "",
+                energy_cost: 0.,
+                actions: Vec::new(),
+                energy: Vec::new(),
+            });
+        }
+        let lane = &mut self.lanes[idx];
+        lane.name = data.character.name;
+        lane.energy_cost = data.character.energy_cost;
+        lane.actions.push((state.current_time, *action));
+        lane.energy.push((state.current_time, state.energy));
+    }
+
+    fn modify(&mut self, source: &'static str, attack: &Attack, before: &State, after: &State) -> () {
+        if before.diff(after).is_empty() {
+            return;
+        }
+        // extend the window unless the buff was not observed for a while
+        let gap = 1.0;
+        let unit_time = self.unit_time;
+        match self.buffs.iter_mut().rev().find(|b| b.source == source) {
+            Some(b) if attack.time - b.end <= gap => b.end = b.end.max(attack.time + unit_time),
+            _ => self.buffs.push(BuffWindow {
+                source,
+                start: attack.time,
+                end: attack.time + unit_time,
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sim2::party::{Party, PartyMember, SimulationConfig};
+    use crate::sim2::record::Enemy;
+
+    fn rotation() -> Rotation {
+        let mut config = SimulationConfig::default();
+        config.simulation_time = 6.0;
+        let mut party = Party::new()
+            .member(PartyMember::find("Diluc", "Prototype Archaic", "Gladiator's Finale").unwrap())
+            .member(PartyMember::find("Bennett", "Prototype Rancour", "Noblesse Oblige").unwrap());
+        let mut rotation = Rotation::new(config.unit_time);
+        party.run_with(&config, &mut Enemy::hilichurl(), &mut rotation).unwrap();
+        rotation
+    }
+
+    #[test]
+    fn ascii() {
+        let rotation = rotation();
+        assert_eq!(rotation.lanes.len(), 2);
+        let chart = rotation.to_ascii();
+        let lines: Vec<&str> = chart.lines().collect();
+        assert!(lines[2].starts_with("Diluc"));
+        assert!(lines[4].starts_with("Bennett"));
+        // Bennett uses the skill and the burst at the beginning
+        assert!(lines[4].contains("EQ"));
+        assert!(rotation.buffs.iter().any(|b| b.source == "Bennett"));
+    }
+
+    #[test]
+    fn svg() {
+        let svg = rotation().to_svg();
+        assert!(svg.starts_with("<svg"));
+        assert!(svg.trim_end().ends_with("</svg>"));
+        assert!(svg.contains("Bennett"));
+    }
+}
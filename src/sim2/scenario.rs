@@ -0,0 +1,176 @@
+use std::error::Error;
+use std::fs;
+
+use crate::sim2::types::Vision;
+use crate::sim2::party::{Party, StartState};
+use crate::sim2::record::Enemy;
+use crate::sim2::sections::{lines, Line, ParseError};
+
+// Starting conditions of members and the enemy, e.g. of the second rotation.
+// Members are given by their indices in the party.
+//
+//     [member 0]
+//     energy = 40
+//     # seconds since the last skill and burst
+//     skill = 4
+//     burst = 15
+//     weapon_stacks = 3
+//     artifact_stacks = 4
+//
+//     [enemy]
+//     # element and units
+//     aura = hydro 1.5
+#[derive(Debug, Clone, Default)]
+pub struct Scenario {
+    pub members: Vec<StartState>,
+    pub aura: Option<(Vision, f32)>,
+}
+
+impl Scenario {
+    pub fn load(path: &str) -> Result<Self, Box<dyn Error>> {
+        Self::parse(&fs::read_to_string(path)?)
+    }
+
+    pub fn parse(text: &str) -> Result<Self, Box<dyn Error>> {
+        let mut scenario = Self::default();
+        // `Some(i)` in a member section and `None` in the enemy section
+        let mut section: Option<Option<usize>> = None;
+        for line in lines(text) {
+            let (n, line) = line?;
+            let (key, value) = match line {
+                Line::Section("enemy") => {
+                    section = Some(None);
+                    continue;
+                },
+                Line::Section(name) => {
+                    let idx: usize = match name.strip_prefix("member ").map(|x| x.trim().parse()) {
+                        Some(Ok(idx)) if idx < 4 => idx,
+                        _ => return Err(Box::new(ParseError::new(n, "section should be [member N] with N of 0, 1, 2 or 3, or [enemy]."))),
+                    };
+                    if scenario.members.len() <= idx {
+                        scenario.members.resize(idx + 1, StartState::default());
+                    }
+                    section = Some(Some(idx));
+                    continue;
+                },
+                Line::Pair(key, value) => (key, value),
+            };
+            match section {
+                Some(Some(idx)) => {
+                    let v: f32 = match value.parse() {
+                        Ok(v) if v >= 0. => v,
+                        _ => return Err(Box::new(ParseError::new(n, &format!("{} should not be negative.", key)))),
+                    };
+                    let start = &mut scenario.members[idx];
+                    match key {
+                        "energy" => start.energy = Some(v),
+                        "skill" => start.skill = Some(v),
+                        "burst" => start.burst = Some(v),
+                        "weapon_stacks" => start.weapon_stacks = Some(v),
+                        "artifact_stacks" => start.artifact_stacks = Some(v),
+                        _ => return Err(Box::new(ParseError::new(n, &format!("unknown key of member: {}", key)))),
+                    };
+                },
+                Some(None) if key == "aura" => {
+                    let mut words = value.split_whitespace();
+                    scenario.aura = match (words.next().and_then(Vision::parse), words.next().and_then(|x| x.parse::<f32>().ok()), words.next()) {
+                        (Some(e), Some(u), None) if e != Vision::Physical && u > 0. => Some((e, u)),
+                        _ => return Err(Box::new(ParseError::new(n, "aura should be an element and units, e.g. `hydro 1.5`."))),
+                    };
+                },
+                Some(None) => return Err(Box::new(ParseError::new(n, &format!("unknown key of enemy: {}", key)))),
+                None => return Err(Box::new(ParseError::new(n, &format!("`{}` should be in [member N] or [enemy].", key)))),
+            };
+        }
+        Ok(scenario)
+    }
+
+    pub fn apply(&self, party: &mut Party) -> Result<(), Box<dyn Error>> {
+        if self.members.len() > party.len() {
+            return Err(Box::new(ParseError::new(0, "the scenario has more members than the party.")));
+        }
+        for (m, start) in party.members.iter_mut().zip(self.members.iter()) {
+            m.start = *start;
+        }
+        Ok(())
+    }
+
+    pub fn enemy(&self, enemy: Enemy) -> Enemy {
+        match self.aura {
+            Some((element, unit)) => enemy.aura(element, unit),
+            None => enemy,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sim2::party::{PartyMember, SimulationConfig};
+    use crate::sim2::attack::DamageResultUtil;
+
+    #[test]
+    fn parse() {
+        let scenario = Scenario::parse("
+[member 1]
+energy = 40
+skill = 4 # the skill is ready in 2 seconds
+weapon_stacks = 3
+
+[enemy]
+aura = hydro 1.5
+").unwrap();
+        assert_eq!(scenario.members.len(), 2);
+        assert_eq!(scenario.members[0], StartState::default());
+        assert_eq!(scenario.members[1].energy, Some(40.));
+        assert_eq!(scenario.members[1].skill, Some(4.));
+        assert_eq!(scenario.members[1].burst, None);
+        assert_eq!(scenario.members[1].weapon_stacks, Some(3.));
+        assert_eq!(scenario.aura, Some((Vision::Hydro, 1.5)));
+        assert!(Scenario::parse("energy = 40").is_err());
+        assert!(Scenario::parse("[member 4]").is_err());
+        assert!(Scenario::parse("[member 0]\nenergy = -1").is_err());
+        assert!(Scenario::parse("[enemy]\naura = physical 1").is_err());
+    }
+
+    #[test]
+    fn starting_conditions() {
+        let config = SimulationConfig::default();
+        let run = |text: &str| {
+            let mut party = Party::new()
+                .member(PartyMember::find("Diluc", "Blackcliff Slasher", "Gladiator's Finale").unwrap());
+            let scenario = Scenario::parse(text).unwrap();
+            scenario.apply(&mut party).unwrap();
+            party.run(&config, &mut scenario.enemy(Enemy::hilichurl())).unwrap().total_damage()
+        };
+        let fresh = run("");
+        assert!(run("[member 0]\nweapon_stacks = 3") > fresh);
+        // the burst is not ready without energy
+        assert!(run("[member 0]\nenergy = 0") < fresh);
+        // the first skill vaporizes the aura
+        assert!(run("[enemy]\naura = hydro 2") > fresh);
+    }
+
+    #[test]
+    fn stacks_of_wielder() {
+        let config = SimulationConfig::default();
+        let run = |text: &str| {
+            let mut party = Party::new()
+                .member(PartyMember::find("Diluc", "Blackcliff Slasher", "Gladiator's Finale").unwrap())
+                .member(PartyMember::find("Xiangling", "Lithic Spear", "Emblem of Severed Fate").unwrap());
+            Scenario::parse(text).unwrap().apply(&mut party).unwrap();
+            let dmg = party.run(&config, &mut Enemy::hilichurl()).unwrap();
+            let damage = |name: &str| dmg.iter().filter(|d| d.name == name).map(|d| d.damage).sum::<f32>();
+            (damage("Diluc"), damage("Xiangling"))
+        };
+        let (diluc, xiangling) = run("");
+        // stacks of Blackcliff buff only Diluc
+        let stacked = run("[member 0]\nweapon_stacks = 3");
+        assert!(stacked.0 > diluc);
+        assert_eq!(stacked.1, xiangling);
+        // stacks of Lithic Spear buff only Xiangling
+        let stacked = run("[member 1]\nweapon_stacks = 4");
+        assert_eq!(stacked.0, diluc);
+        assert!(stacked.1 > xiangling);
+    }
+}
@@ -0,0 +1,495 @@
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use crate::sim2::characters;
+use crate::sim2::weapons;
+use crate::sim2::artifact;
+use crate::sim2::attack::DamageResultUtil;
+use crate::sim2::party::{Party, PartyError, PartyMember, SimulationConfig};
+use crate::sim2::record::{CharacterRecord, WeaponRecord, Artifact, ArtifactLoadout, Enemy};
+use crate::sim2::types::{Versions, combination_filter};
+use crate::sim2::util::Rng;
+
+// a member of a candidate team: indices into the lists of `SearchSpace`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Gene {
+    pub character: usize,
+    pub weapon: usize,
+    pub artifact: usize,
+}
+
+// The first gene is the on-field member.
+pub type Candidate = Vec<Gene>;
+
+// The order of supporters does not change the team, so they are sorted to
+// share the score, e.g. [A, B, C] and [A, C, B].
+fn team(c: &Candidate) -> Candidate {
+    let mut c = c.clone();
+    if c.len() > 1 {
+        c[1..].sort_unstable();
+    }
+    c
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct Budget {
+    pub evaluations: usize,
+    pub time: Option<Duration>,
+}
+
+impl Default for Budget {
+    fn default() -> Self {
+        Self {
+            evaluations: 2000,
+            time: None,
+        }
+    }
+}
+
+// Records and unions are not `Clone`, so each member slot takes them from its
+// own copy of the lists and puts them back after the simulation.
+struct Pool {
+    characters: Vec<Option<(CharacterRecord, characters::CharacterUnion)>>,
+    weapons: Vec<Option<(WeaponRecord, weapons::WeaponUnion)>>,
+    artifacts: Vec<Option<(Artifact, artifact::ArtifactUnion)>>,
+}
+
+impl Pool {
+    fn new() -> Self {
+        Self {
+            characters: characters::all().into_iter().map(Some).collect(),
+            weapons: weapons::all().into_iter().map(Some).collect(),
+            artifacts: artifact::all().into_iter().map(Some).collect(),
+        }
+    }
+
+    fn take(&mut self, g: &Gene) -> PartyMember {
+        PartyMember::new(
+            self.characters[g.character].take().unwrap(),
+            self.weapons[g.weapon].take().unwrap(),
+            self.artifacts[g.artifact].take().unwrap(),
+        )
+    }
+
+    fn back(&mut self, g: &Gene, m: PartyMember) -> () {
+        self.characters[g.character] = Some(m.character);
+        self.weapons[g.weapon] = Some(m.weapon);
+        self.artifacts[g.artifact] = Some(m.artifact);
+    }
+}
+
+// Teams, weapons and artifacts that can be searched, and the total damage of
+// candidates evaluated so far.
+pub struct SearchSpace {
+    pub n_members: usize,
+    pub config: SimulationConfig,
+    pub loadout: ArtifactLoadout,
+    pub support_loadout: ArtifactLoadout,
+    pools: Vec<Pool>,
+    // characters that have at least one weapon and artifact
+    characters: Vec<usize>,
+    // weapons of each character that have at least one artifact, and the
+    // artifacts of each of those weapons
+    weapons_of: Vec<Vec<usize>>,
+    artifacts_of: Vec<Vec<Vec<usize>>>,
+    names: (Vec<&'static str>, Vec<&'static str>, Vec<&'static str>),
+    scores: HashMap<Candidate, f32>,
+    evaluations: usize,
+    start: Instant,
+}
+
+impl SearchSpace {
+    pub fn new(n_members: usize, versions: &Versions, config: SimulationConfig) -> Result<Self, PartyError> {
+        if n_members == 0 || n_members > 4 {
+            return Err(PartyError::new(&format!("party should have 1 to 4 members, not {}.", n_members)));
+        }
+        let pools: Vec<Pool> = (0..n_members).map(|_| Pool::new()).collect();
+        let pool = &pools[0];
+        let mut characters: Vec<usize> = Vec::new();
+        let mut weapons_of: Vec<Vec<usize>> = Vec::new();
+        let mut artifacts_of: Vec<Vec<Vec<usize>>> = Vec::new();
+        for (ci, c) in pool.characters.iter().enumerate() {
+            let (cr, _) = c.as_ref().unwrap();
+            let mut ws: Vec<usize> = Vec::new();
+            let mut as_: Vec<Vec<usize>> = Vec::new();
+            for (wi, w) in pool.weapons.iter().enumerate() {
+                let wr = &w.as_ref().unwrap().0;
+                let artifacts: Vec<usize> = pool.artifacts.iter().enumerate()
+                    .filter(|(_, a)| combination_filter(cr, wr, &a.as_ref().unwrap().0, versions))
+                    .map(|(i, _)| i)
+                    .collect();
+                if !artifacts.is_empty() {
+                    ws.push(wi);
+                    as_.push(artifacts);
+                }
+            }
+            if !ws.is_empty() {
+                characters.push(ci);
+            }
+            weapons_of.push(ws);
+            artifacts_of.push(as_);
+        }
+        if characters.len() < n_members {
+            return Err(PartyError::new(&format!("{} characters are available for parties of {} members.", characters.len(), n_members)));
+        }
+        let names = (
+            pool.characters.iter().map(|x| x.as_ref().unwrap().0.name).collect(),
+            pool.weapons.iter().map(|x| x.as_ref().unwrap().0.name).collect(),
+            pool.artifacts.iter().map(|x| x.as_ref().unwrap().0.name).collect(),
+        );
+        Ok(Self {
+            n_members,
+            config,
+            loadout: ArtifactLoadout::default(),
+            support_loadout: ArtifactLoadout::default(),
+            pools,
+            characters,
+            weapons_of,
+            artifacts_of,
+            names,
+            scores: HashMap::new(),
+            evaluations: 0,
+            start: Instant::now(),
+        })
+    }
+
+    pub fn loadout(mut self, loadout: ArtifactLoadout) -> Self { self.loadout = loadout; self }
+    pub fn support_loadout(mut self, loadout: ArtifactLoadout) -> Self { self.support_loadout = loadout; self }
+
+    pub fn evaluations(&self) -> usize {
+        self.evaluations
+    }
+
+    pub fn exhausted(&self, budget: &Budget) -> bool {
+        self.evaluations >= budget.evaluations || match budget.time {
+            Some(t) => self.start.elapsed() >= t,
+            None => false,
+        }
+    }
+
+    pub fn is_evaluated(&self, c: &Candidate) -> bool {
+        self.scores.contains_key(&team(c))
+    }
+
+    // the total damage of the team. Every call counts as an evaluation of the
+    // budget, but each team is simulated only once.
+    pub fn evaluate(&mut self, c: &Candidate) -> f32 {
+        self.evaluations += 1;
+        let c = &team(c);
+        if let Some(score) = self.scores.get(c) {
+            return *score;
+        }
+        let mut party = Party::new();
+        for (slot, g) in c.iter().enumerate() {
+            let loadout = if slot == 0 { self.loadout } else { self.support_loadout };
+            party = party.member(self.pools[slot].take(g).loadout(loadout));
+        }
+        // the size of candidates is checked by `SearchSpace::new`
+        let score = party.run(&self.config, &mut Enemy::hilichurl()).unwrap().total_damage();
+        for (slot, (g, m)) in c.iter().zip(party.members.into_iter()).enumerate() {
+            self.pools[slot].back(g, m);
+        }
+        self.scores.insert(c.clone(), score);
+        score
+    }
+
+    // the best `k` teams evaluated so far
+    pub fn best(&self, k: usize) -> Vec<(Candidate, f32)> {
+        let mut result: Vec<(Candidate, f32)> = self.scores.iter().map(|(c, s)| (c.clone(), *s)).collect();
+        result.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap());
+        result.truncate(k);
+        result
+    }
+
+    // character, weapon and artifact names of each member
+    pub fn names(&self, c: &Candidate) -> Vec<&'static str> {
+        let mut result: Vec<&'static str> = Vec::with_capacity(3 * c.len());
+        for g in c.iter() {
+            result.push(self.names.0[g.character]);
+            result.push(self.names.1[g.weapon]);
+            result.push(self.names.2[g.artifact]);
+        }
+        result
+    }
+
+    // artifacts that `character` can equip with `weapon`
+    fn artifacts_of(&self, character: usize, weapon: usize) -> &[usize] {
+        match self.weapons_of[character].iter().position(|&w| w == weapon) {
+            Some(i) => &self.artifacts_of[character][i],
+            None => &[],
+        }
+    }
+
+    fn random_artifact(&self, character: usize, weapon: usize, rng: &mut Rng) -> usize {
+        let as_ = self.artifacts_of(character, weapon);
+        as_[rng.below(as_.len())]
+    }
+
+    fn random_gene(&self, character: usize, rng: &mut Rng) -> Gene {
+        let ws = &self.weapons_of[character];
+        let weapon = ws[rng.below(ws.len())];
+        Gene {
+            character,
+            weapon,
+            artifact: self.random_artifact(character, weapon, rng),
+        }
+    }
+
+    // a character who is not a member of `c`. `SearchSpace::new` checks that
+    // there are enough characters.
+    fn random_character(&self, c: &Candidate, rng: &mut Rng) -> usize {
+        loop {
+            let ci = self.characters[rng.below(self.characters.len())];
+            if c.iter().all(|g| g.character != ci) {
+                return ci;
+            }
+        }
+    }
+
+    pub fn random_candidate(&self, rng: &mut Rng) -> Candidate {
+        let mut c: Candidate = Vec::with_capacity(self.n_members);
+        for _ in 0..self.n_members {
+            let ci = self.random_character(&c, rng);
+            c.push(self.random_gene(ci, rng));
+        }
+        c
+    }
+
+    // change the character, weapon or artifact of a member
+    pub fn mutate(&self, c: &Candidate, rng: &mut Rng) -> Candidate {
+        let mut c = c.clone();
+        let slot = rng.below(c.len());
+        let g = c[slot];
+        c[slot] = match rng.below(3) {
+            0 => {
+                let ci = self.random_character(&c, rng);
+                let mut new = self.random_gene(ci, rng);
+                // keep the gear if the new character can use it
+                if self.weapons_of[ci].contains(&g.weapon) {
+                    new.weapon = g.weapon;
+                    new.artifact = self.random_artifact(ci, g.weapon, rng);
+                }
+                if self.artifacts_of(ci, new.weapon).contains(&g.artifact) {
+                    new.artifact = g.artifact;
+                }
+                new
+            },
+            1 => {
+                let new = self.random_gene(g.character, rng);
+                if self.artifacts_of(g.character, new.weapon).contains(&g.artifact) {
+                    Gene { weapon: new.weapon, ..g }
+                } else {
+                    new
+                }
+            },
+            _ => Gene { artifact: self.random_artifact(g.character, g.weapon, rng), ..g },
+        };
+        c
+    }
+
+    // take each member from either parent. Members whose character is
+    // already in the team are replaced with random ones.
+    pub fn crossover(&self, a: &Candidate, b: &Candidate, rng: &mut Rng) -> Candidate {
+        let mut c: Candidate = Vec::with_capacity(a.len());
+        for (ga, gb) in a.iter().zip(b.iter()) {
+            let g = if rng.chance() < 0.5 { *ga } else { *gb };
+            if c.iter().any(|x| x.character == g.character) {
+                let ci = self.random_character(&c, rng);
+                c.push(self.random_gene(ci, rng));
+            } else {
+                c.push(g);
+            }
+        }
+        c
+    }
+}
+
+pub trait SearchStrategy {
+    // evaluate candidates until the budget is exhausted or the search
+    // converges. Results are collected by `SearchSpace::best`.
+    fn search(&mut self, space: &mut SearchSpace, budget: &Budget) -> ();
+}
+
+// Keeps the `width` best teams and moves to `branch` neighbours (teams with
+// one character, weapon or artifact changed) of each of them.
+#[derive(Debug, Clone)]
+pub struct BeamSearch {
+    pub width: usize,
+    pub branch: usize,
+    pub rng: Rng,
+}
+
+impl BeamSearch {
+    pub fn new(width: usize, branch: usize, seed: u64) -> Self {
+        Self {
+            width,
+            branch,
+            rng: Rng::new(seed),
+        }
+    }
+}
+
+impl SearchStrategy for BeamSearch {
+    fn search(&mut self, space: &mut SearchSpace, budget: &Budget) -> () {
+        let mut beam: Vec<(Candidate, f32)> = Vec::with_capacity(self.width * (self.branch + 1));
+        while beam.len() < self.width && !space.exhausted(budget) {
+            let c = space.random_candidate(&mut self.rng);
+            let score = space.evaluate(&c);
+            beam.push((c, score));
+        }
+        while !space.exhausted(budget) {
+            let mut next: Vec<(Candidate, f32)> = Vec::new();
+            for (c, _) in beam.iter() {
+                for _ in 0..self.branch {
+                    let n = space.mutate(c, &mut self.rng);
+                    if space.is_evaluated(&n) || space.exhausted(budget) {
+                        continue;
+                    }
+                    let score = space.evaluate(&n);
+                    next.push((n, score));
+                }
+            }
+            if next.is_empty() {
+                break;
+            }
+            beam.append(&mut next);
+            beam.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap());
+            beam.truncate(self.width);
+        }
+    }
+}
+
+// A genetic algorithm with tournament selection, uniform crossover of
+// members and elitism.
+#[derive(Debug, Clone)]
+pub struct Genetic {
+    pub population: usize,
+    pub mutation: f32,
+    pub rng: Rng,
+}
+
+impl Genetic {
+    pub fn new(population: usize, mutation: f32, seed: u64) -> Self {
+        Self {
+            population,
+            mutation,
+            rng: Rng::new(seed),
+        }
+    }
+
+    fn select<'a>(&mut self, population: &'a [(Candidate, f32)]) -> &'a Candidate {
+        let a = &population[self.rng.below(population.len())];
+        let b = &population[self.rng.below(population.len())];
+        if a.1 >= b.1 { &a.0 } else { &b.0 }
+    }
+}
+
+impl SearchStrategy for Genetic {
+    fn search(&mut self, space: &mut SearchSpace, budget: &Budget) -> () {
+        let mut population: Vec<(Candidate, f32)> = Vec::with_capacity(self.population);
+        while population.len() < self.population && !space.exhausted(budget) {
+            let c = space.random_candidate(&mut self.rng);
+            let score = space.evaluate(&c);
+            population.push((c, score));
+        }
+        while !space.exhausted(budget) {
+            population.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap());
+            let mut next: Vec<(Candidate, f32)> = Vec::with_capacity(self.population);
+            // the best team always survives
+            next.push(population[0].clone());
+            while next.len() < self.population && !space.exhausted(budget) {
+                let a = self.select(&population).clone();
+                let b = self.select(&population).clone();
+                let mut c = space.crossover(&a, &b, &mut self.rng);
+                if self.rng.chance() < self.mutation {
+                    c = space.mutate(&c, &mut self.rng);
+                }
+                let score = space.evaluate(&c);
+                next.push((c, score));
+            }
+            population = next;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn space(n_members: usize) -> SearchSpace {
+        let mut config = SimulationConfig::default();
+        config.simulation_time = 4.0;
+        SearchSpace::new(n_members, &Versions::default(), config).unwrap()
+    }
+
+    fn is_valid(space: &SearchSpace, c: &Candidate) -> bool {
+        c.iter().enumerate().all(|(i, g)| {
+            space.artifacts_of(g.character, g.weapon).contains(&g.artifact)
+            && c[..i].iter().all(|x| x.character != g.character)
+        })
+    }
+
+    #[test]
+    fn too_few_characters() {
+        let versions = Versions { character: 0.0, ..Versions::default() };
+        assert!(SearchSpace::new(2, &versions, SimulationConfig::default()).is_err());
+        assert!(SearchSpace::new(5, &Versions::default(), SimulationConfig::default()).is_err());
+    }
+
+    #[test]
+    fn random_and_mutated_candidates_are_valid() {
+        let space = space(3);
+        let mut rng = Rng::new(1);
+        for _ in 0..100 {
+            let a = space.random_candidate(&mut rng);
+            let b = space.random_candidate(&mut rng);
+            assert!(is_valid(&space, &a));
+            assert!(is_valid(&space, &space.mutate(&a, &mut rng)));
+            assert!(is_valid(&space, &space.crossover(&a, &b, &mut rng)));
+        }
+    }
+
+    #[test]
+    fn beam_search_budget() {
+        let mut space = space(2);
+        let budget = Budget { evaluations: 30, time: None };
+        BeamSearch::new(4, 3, 1).search(&mut space, &budget);
+        assert_eq!(space.evaluations(), 30);
+        let best = space.best(3);
+        assert_eq!(best.len(), 3);
+        assert!(best[0].1 >= best[1].1 && best[1].1 >= best[2].1);
+        assert_eq!(space.names(&best[0].0).len(), 6);
+    }
+
+    #[test]
+    fn genetic_improves_random_teams() {
+        // the average of random teams
+        let mut random = space(2);
+        let mut rng = Rng::new(3);
+        let average = (0..20).map(|_| {
+            let c = random.random_candidate(&mut rng);
+            random.evaluate(&c)
+        }).sum::<f32>() / 20.;
+        let mut space = space(2);
+        let budget = Budget { evaluations: 20, time: None };
+        let mut strategy = Genetic::new(10, 0.3, 7);
+        strategy.search(&mut space, &budget);
+        let initial = space.best(1)[0].1;
+        strategy.search(&mut space, &Budget { evaluations: 80, time: None });
+        let best = space.best(1)[0].1;
+        assert!(best > initial);
+        assert!(best > average);
+    }
+
+    #[test]
+    fn order_of_supporters() {
+        let mut space = space(3);
+        let mut rng = Rng::new(5);
+        let a = space.random_candidate(&mut rng);
+        let b = vec![a[0], a[2], a[1]];
+        let score = space.evaluate(&a);
+        assert!(space.is_evaluated(&b));
+        assert_eq!(space.evaluate(&b), score);
+        assert_eq!(space.best(10).len(), 1);
+    }
+}
@@ -0,0 +1,76 @@
+use std::error::Error;
+use std::fmt;
+
+#[derive(Debug)]
+pub struct ParseError {
+    details: String
+}
+
+impl ParseError {
+    // `line` is 1-based, and 0 is the whole text
+    pub fn new(line: usize, msg: &str) -> ParseError {
+        ParseError { details: format!("line {}: {}", line, msg) }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f,"{}",self.details)
+    }
+}
+
+impl Error for ParseError {
+    fn description(&self) -> &str {
+        &self.details
+    }
+}
+
+// A line of texts like chambers and scenarios, which consist of `[section]`
+// headers and `key = value` pairs. Comments start with `#`.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Line<'a> {
+    // the name between the brackets
+    Section(&'a str),
+    Pair(&'a str, &'a str),
+}
+
+// lines of `text` with their line numbers. Comments and empty lines are
+// skipped.
+pub fn lines(text: &str) -> impl Iterator<Item = Result<(usize, Line<'_>), ParseError>> {
+    text.lines().enumerate().filter_map(|(i, line)| {
+        let n = i + 1;
+        let line = match line.find('#') {
+            Some(j) => &line[..j],
+            None => line,
+        }.trim();
+        if line.is_empty() {
+            return None;
+        }
+        if let Some(name) = line.strip_prefix('[').and_then(|x| x.strip_suffix(']')) {
+            return Some(Ok((n, Line::Section(name.trim()))));
+        }
+        Some(match line.split_once('=') {
+            Some((k, v)) => Ok((n, Line::Pair(k.trim(), v.trim()))),
+            None => Err(ParseError::new(n, "expected `key = value`.")),
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_lines() {
+        let text = "
+# comment
+[member 0]
+energy = 40 # seconds
+weapon";
+        let lines: Vec<Result<(usize, Line), ParseError>> = lines(text).collect();
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[0].as_ref().unwrap(), &(3, Line::Section("member 0")));
+        assert_eq!(lines[1].as_ref().unwrap(), &(4, Line::Pair("energy", "40")));
+        assert_eq!(lines[2].as_ref().unwrap_err().to_string(), "line 5: expected `key = value`.");
+    }
+}
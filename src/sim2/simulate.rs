@@ -1,8 +1,12 @@
 use crate::sim2::state::State;
 use crate::sim2::timeline::ActionState;
 use crate::sim2::attack::{Attack, DamageResult, CharacterAttack, WeaponAttack};
-use crate::sim2::types::{CharacterAction, DamageType, Vision, FieldCharacterIndex, FieldEnergy};
+use crate::sim2::types::{CharacterAction, DamageType, Vision, FieldCharacterIndex, FieldEnergy, Particle, ParticleMode};
+use crate::sim2::util::Rng;
 use crate::sim2::record::{TimelineMember, FieldMember, CharacterData, Enemy};
+use crate::sim2::trace::{Tracer, NoTrace};
+use crate::sim2::element::ElementalReaction;
+use crate::sim2::event::{Event, TimedEvent, EnergyGain};
 
 // why? to reuse allocations for all characters, because if `end_time` and
 // `unit_time` are fixed, all histories have the same size.
@@ -12,6 +16,15 @@ pub struct History<const N: usize> {
     pub unit_time: f32,
     pub action: Vec<[CharacterAction; N]>,
     pub state: Vec<[ActionState; N]>,
+    // events of timelines ordered by time
+    pub events: Vec<TimedEvent>,
+    // energy received by members in the order of time
+    pub energy: Vec<EnergyGain>,
+    // members are refilled with energy after every action
+    pub unlimited_energy: bool,
+    pub particle: ParticleMode,
+    // seconds until particles reach members
+    pub particle_delay: f32,
 }
 
 impl<const N: usize> History<N> {
@@ -22,6 +35,11 @@ impl<const N: usize> History<N> {
             unit_time,
             action: Vec::with_capacity(size),
             state: vec![[ActionState::new(); N]; size],
+            events: Vec::new(),
+            energy: Vec::new(),
+            unlimited_energy: false,
+            particle: ParticleMode::Expected,
+            particle_delay: 0.,
         }
     }
 
@@ -31,6 +49,29 @@ impl<const N: usize> History<N> {
     }
 }
 
+// Identifies the inputs of `decide_action`. Weapons and artifacts that do not
+// accelerate affect timelines only through atk_spd and ER, so a `History`
+// can be reused for all of them if these stats are the same.
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct TimelineKey(Vec<(&'static str, u32, u32)>);
+
+impl TimelineKey {
+    // `None` if any weapon or artifact accelerates, because they may also
+    // record states for `attack` and `modify` (e.g. Shimenawa's Reminiscence).
+    pub fn new(members: &[TimelineMember], data: &[CharacterData]) -> Option<Self> {
+        let mut key = Vec::with_capacity(data.len());
+        for (m, d) in members.iter().zip(data.iter()) {
+            if m.weapon.accelerates() || m.artifact.accelerates() {
+                return None;
+            }
+            let atk_spd = d.weapon.atk_spd + d.artifact.atk_spd;
+            let er = d.weapon.er + d.artifact.er;
+            key.push((d.character.name, atk_spd.to_bits(), er.to_bits()));
+        }
+        Some(Self(key))
+    }
+}
+
 // the "delay time" is 3 seconds
 #[derive(Debug)]
 struct Policy<'a> {
@@ -73,12 +114,27 @@ impl<'a> Policy<'a> {
 
 pub fn decide_action<const N: usize>(history: &mut History<N>, members: &mut [TimelineMember; N], states: &mut [ActionState; N], data: &mut [CharacterData; N]) -> () {
     let mut field_energy: Vec<FieldEnergy> = Vec::new();
+    // names of the sources of `field_energy`
+    let mut field_source: Vec<&'static str> = Vec::new();
+    // indices of the members who generated `field_energy`
+    let mut field_owner: Vec<usize> = Vec::new();
+    // particles that are flying to members: the time when they were
+    // generated, when they land, the particles and their source
+    let mut particles: Vec<(f32, f32, Particle, &'static str)> = Vec::new();
+    let mut rng = match history.particle {
+        ParticleMode::Sampled(seed) => Some(Rng::new(seed)),
+        ParticleMode::Expected => None,
+    };
     let mut current_time: f32 = 0.;
     let mut idx = 0;
     while current_time <= history.end_time {
         let mut actions = [CharacterAction::StandStill; N];
         for (i, member) in members.iter_mut().enumerate() {
-            let action = {
+            // nothing can be performed during movements, so characters do
+            // not decide actions then
+            let action = if states[i].is_moving() {
+                CharacterAction::StandStill
+            } else {
                 let a = member.character.decide_action(&states[i], &mut data[i]);
                 if N == 1 {
                     a
@@ -91,49 +147,153 @@ pub fn decide_action<const N: usize>(history: &mut History<N>, members: &mut [Ti
                     }
                 }
             };
+            // charged attacks and dashes cannot be performed without enough
+            // stamina. characters also check it before they change their
+            // states for these actions (see `ActionState::can_ca`).
+            let action = if states[i].has_stamina(&action) {
+                action
+            } else {
+                CharacterAction::StandStill
+            };
             let state = &mut states[i];
             let d = &data[i];
+            if action == CharacterAction::Burst {
+                history.events.push(TimedEvent::new(current_time, Event::Burst { idx: d.idx, energy: d.character.energy_cost }));
+            }
+            if i != 0 && (action.is_skill() || action.is_burst()) {
+                history.events.push(TimedEvent::new(current_time, Event::Swap { from: data[0].idx, to: d.idx }));
+                history.events.push(TimedEvent::new(current_time + history.unit_time, Event::Swap { from: d.idx, to: data[0].idx }));
+            }
             state.update1(&action, current_time, history.unit_time);
             history.state[idx][i].copy(&state);
             // state.current_time += history.unit_time;
             state.rel_time.add(history.unit_time);
             state.init(d);
             member.character.accelerate(&mut field_energy, &action, state, d);
+            field_source.resize(field_energy.len(), d.character.name);
             member.weapon.accelerate(&mut field_energy, &action, state, d);
+            field_source.resize(field_energy.len(), d.weapon.name);
             member.artifact.accelerate(&mut field_energy, &action, state, d);
+            field_source.resize(field_energy.len(), d.artifact.name);
+            field_owner.resize(field_energy.len(), i);
             actions[i] = action;
         }
+        for (fe, source) in field_energy.iter().zip(field_source.iter()) {
+            if let FieldEnergy::Particle(ref p) = fe {
+                let p = match rng {
+                    Some(ref mut rng) => p.sample(rng),
+                    None => *p,
+                };
+                particles.push((current_time, current_time + history.particle_delay, p, source));
+            }
+        }
+        // particles that land in this tick. those that land as soon as they
+        // are generated are received before the member swaps in, and the
+        // others are received by the member on the field when they land.
+        let landed = particles.iter().take_while(|(_, arrival, _, _)| *arrival < current_time + 0.5 * history.unit_time).count();
+        let active = (1..N).find(|i| actions[*i].is_skill() || actions[*i].is_burst()).unwrap_or(0);
+        let visions: Vec<Vision> = data.iter().map(|d| d.character.vision).collect();
         for (i, member) in members.iter_mut().enumerate() {
             let mut energy: f32 = 0.;
-            for fe in field_energy.iter() {
-                match fe {
-                    FieldEnergy::Particle(ref p) => energy += if i == 0 {
-                        p.on_field_energy(&data[i].character.vision) * states[i].er()
-                    } else {
-                        p.off_field_energy(&data[i].character.vision) * states[i].er()
-                    },
-                    FieldEnergy::Energy(e) => energy += e,
+            let er = states[i].er();
+            // flat energy is received in full by its recipients regardless of
+            // the party size
+            for ((fe, source), owner) in field_energy.iter().zip(field_source.iter()).zip(field_owner.iter()) {
+                if let FieldEnergy::Energy(e, recipient) = fe {
+                    if !recipient.includes(i, *owner, &visions) {
+                        continue;
+                    }
+                    let gain = EnergyGain { time: current_time, idx: data[i].idx, source, particle: 0., flat: *e, er };
+                    energy += gain.energy();
+                    history.energy.push(gain);
                 }
             }
+            for (generated, arrival, p, source) in particles[..landed].iter() {
+                let on_field = if *arrival <= *generated { i == 0 } else { i == active };
+                let particle = if on_field {
+                    p.on_field_energy(&data[i].character.vision)
+                } else {
+                    p.off_field_energy(&data[i].character.vision, N)
+                };
+                let gain = EnergyGain { time: current_time, idx: data[i].idx, source, particle, flat: 0., er };
+                history.events.push(TimedEvent::new(current_time, Event::Particle { idx: data[i].idx, energy: gain.energy() }));
+                energy += gain.energy();
+                history.energy.push(gain);
+            }
             states[i].update2(&actions[i], current_time + history.unit_time, history.unit_time, energy);
+            if history.unlimited_energy {
+                states[i].energy = states[i].energy.max(data[i].character.energy_cost);
+            }
             data[i].reset_na(&actions[i]);
         }
+        particles.drain(..landed);
         history.action.push(actions);
         field_energy.clear();
+        field_source.clear();
+        field_owner.clear();
         current_time += history.unit_time;
         idx += 1;
     }
+    // swaps back to the on-field member are pushed ahead of time
+    history.events.sort_by(|a, b| a.time.partial_cmp(&b.time).unwrap());
+}
+
+// report debuffs applied after `applied` applications
+fn trace_debuffs<T: Tracer>(tracer: &mut T, enemy: &Enemy, applied: &mut usize) -> () {
+    let n = (enemy.applied_debuffs - *applied).min(enemy.debuffs.len());
+    for d in enemy.debuffs[enemy.debuffs.len() - n..].iter() {
+        tracer.debuff(d);
+    }
+    *applied = enemy.applied_debuffs;
+}
+
+fn deliver<const N: usize>(members: &mut [FieldMember; N], data: &[CharacterData; N], time: f32, event: &Event) -> () {
+    for (member, d) in members.iter_mut().zip(data.iter()) {
+        deliver_to(member, d, time, event);
+    }
+}
+
+fn deliver_to(member: &mut FieldMember, data: &CharacterData, time: f32, event: &Event) -> () {
+    member.character.event(time, event, data);
+    member.weapon.event(time, event, data);
+    member.artifact.event(time, event, data);
 }
 
 pub fn calculate_damage<const N: usize>(history: &mut History<N>, members: &mut [FieldMember; N], data: &[CharacterData; N], enemy: &mut Enemy) -> Vec<DamageResult> {
+    calculate_damage_with(history, members, data, enemy, &mut NoTrace)
+}
+
+// `calculate_damage` that reports actions, attacks, changes of states and
+// damages to `tracer`
+pub fn calculate_damage_with<T: Tracer, const N: usize>(history: &mut History<N>, members: &mut [FieldMember; N], data: &[CharacterData; N], enemy: &mut Enemy, tracer: &mut T) -> Vec<DamageResult> {
+    for (state, event) in history.state.iter().zip(history.action.iter()) {
+        for i in 0..N {
+            tracer.action(&data[i], &event[i], &state[i]);
+        }
+    }
+    for gain in history.energy.iter() {
+        tracer.energy(gain);
+    }
     let mut atk_queue: Vec<Attack> = Vec::new();
     let mut states = [State::default(); N];
     for i in 0..N {
         let member = &mut members[i];
+        let d = &data[i];
+        let mut events = history.events.iter().peekable();
         for (state, event) in history.state.iter().zip(history.action.iter()) {
-            member.character.attack(state[i].current_time, &event[i], &data[i], &mut atk_queue, &mut states[i], enemy);
-            member.weapon.attack(state[i].current_time, &event[i], &data[i], &mut atk_queue, &mut states[i], enemy);
-            member.artifact.attack(state[i].current_time, &event[i], &data[i], &mut atk_queue, &mut states[i], enemy);
+            let time = state[i].current_time;
+            while let Some(e) = events.next_if(|e| e.time <= time) {
+                deliver_to(member, d, e.time, &e.event);
+            }
+            let n = atk_queue.len();
+            member.character.attack(time, &event[i], d, &mut atk_queue, &mut states[i], enemy);
+            tracer.queue(time, d.character.name, &atk_queue[n..]);
+            let n = atk_queue.len();
+            member.weapon.attack(time, &event[i], d, &mut atk_queue, &mut states[i], enemy);
+            tracer.queue(time, d.weapon.name, &atk_queue[n..]);
+            let n = atk_queue.len();
+            member.artifact.attack(time, &event[i], d, &mut atk_queue, &mut states[i], enemy);
+            tracer.queue(time, d.artifact.name, &atk_queue[n..]);
         }
     }
     for i in 0..N {
@@ -154,23 +314,60 @@ pub fn calculate_damage<const N: usize>(history: &mut History<N>, members: &mut
         }
         let state = &mut states[attack.idx.0];
         state.init(&data[attack.idx.0]);
+        let mut applied_debuffs = enemy.applied_debuffs;
         for i in 0..N {
             // character state first
             let d = &data[i];
             let member = &mut members[i];
             let action_state = &history.state[history.state_index(attack.time)][i];
+            let before = *state;
             member.character.modify(action_state, d, &mut attack, state, enemy);
+            tracer.modify(d.character.name, &attack, &before, state);
+            let before = *state;
             member.weapon.modify(action_state, d, &mut attack, state, enemy);
+            tracer.modify(d.weapon.name, &attack, &before, state);
+            let before = *state;
             member.artifact.modify(action_state, d, &mut attack, state, enemy);
+            tracer.modify(d.artifact.name, &attack, &before, state);
         }
+        trace_debuffs(tracer, enemy, &mut applied_debuffs);
         let state = &states[attack.idx.0];
         let d = &data[attack.idx.0];
+        let (time, idx, kind) = (attack.time, attack.idx, attack.kind);
+        let mut reaction_name = None;
+        if attack.aura_application && attack.kind != DamageType::FlatDMG {
+            let reaction = ElementalReaction::new(enemy.aura.aura, attack.element.aura);
+            if reaction.is_reaction() {
+                tracer.reaction(&attack, &reaction);
+                reaction_name = Some(reaction.name());
+            }
+        }
+        let rate = (state.cr / 100.).max(0.).min(1.);
         let dmg = DamageResult::new(attack, state, d, enemy);
+        trace_debuffs(tracer, enemy, &mut applied_debuffs);
+        tracer.damage(&dmg);
         // println!("{:?} {:?} {:?}", dmg.time, dmg.kind, dmg.total_damage());
         // if dmg.kind != DamageType::Na && dmg.kind != DamageType::Ca {
         //     result.push(dmg);
         // }
+        enemy.take_damage(time, dmg.total_damage());
         result.push(dmg);
+        // the rest of the attacks hit nothing
+        if enemy.is_cleared() {
+            break;
+        }
+        if kind == DamageType::FlatDMG {
+            continue;
+        }
+        if kind == DamageType::Skill {
+            deliver(members, data, time, &Event::SkillHit { idx });
+        }
+        if rate > 0. {
+            deliver(members, data, time, &Event::Crit { idx, rate });
+        }
+        if let Some(name) = reaction_name {
+            deliver(members, data, time, &Event::Reaction { idx, name });
+        }
     }
     result
 }
@@ -182,10 +379,10 @@ mod tests {
     use crate::sim2::testutil;
     use crate::sim2::testutil::{Sim2TestCharacter, NoopTimeline};
     use crate::sim2::element::{ElementalGauge, ElementalGaugeDecay};
-    use crate::sim2::types::{Vision};
+    use crate::sim2::types::{Vision, WeaponType};
     use crate::sim2::attack::{DamageResultUtil};
     use crate::sim2::timeline::{ActionColumn, Timeline};
-    use crate::sim2::record::{WeaponRecord, Artifact};
+    use crate::sim2::record::{WeaponRecord, Artifact, ArtifactLoadout, MainStat};
 
     use Vision::*;
 
@@ -234,6 +431,31 @@ mod tests {
         assert_eq!(dmg, expect);
     }
 
+    #[test]
+    fn waves() {
+        let mut history = testutil::history_7at02();
+        // the burst kills the first, and the skill kills the second
+        let mut enemy = Enemy::simple().waves(&[300., 150.]);
+        let mut character = Sim2TestCharacter::new();
+        let mut weapon = WeaponRecord::default();
+        let mut artifact = Artifact::default();
+        let mut members = [FieldMember {
+            character: &mut character,
+            weapon: &mut weapon,
+            artifact: &mut artifact,
+        }; 1];
+        let cr = Sim2TestCharacter::record(Pyro);
+        let wr = WeaponRecord::default();
+        let ar = Artifact::default();
+        let mut data = [CharacterData::new(0, &cr, &wr, &ar); 1];
+        assert_eq!(enemy.hp_ratio(), 1.);
+        let dmg = calculate_damage(&mut history, &mut members, &mut data, &mut enemy);
+        assert!(enemy.is_cleared());
+        assert_eq!(enemy.kills.len(), 2);
+        assert_eq!(enemy.clear_time(), Some(dmg.last().unwrap().time));
+        assert!(dmg.last().unwrap().time < 7.0);
+    }
+
     #[test]
     fn infuse_goblet() {
         let mut history = testutil::history_7at02();
@@ -255,6 +477,23 @@ mod tests {
         assert_eq!(dmg, expect);
     }
 
+    #[test]
+    fn equip_main_stats() {
+        let cr = Sim2TestCharacter::record(Pyro);
+        let mut ar = Artifact::default().pyro_dmg(10.0);
+        let loadout = ArtifactLoadout::new(Some(MainStat::Er), Some(MainStat::DmgBonus), None);
+        let bare = ar.clone();
+        ar.equip(&loadout, &cr.vision, &cr.name);
+        // the goblet is added to the set bonus instead of overwriting it
+        assert!((ar.pyro_dmg - 56.6).abs() < 1e-4);
+        assert!((ar.er - 51.8).abs() < 1e-4);
+        assert_eq!(ar.flat_atk, 311.0);
+        // the plume is added to flat ATK of the set
+        let mut ar = bare.flat_atk(20.0);
+        ar.equip(&loadout, &cr.vision, &cr.name);
+        assert_eq!(ar.flat_atk, 331.0);
+    }
+
     #[test]
     fn attack_infusion() {
         let mut history = testutil::history_7at02();
@@ -385,6 +624,103 @@ mod tests {
         assert_eq!(history.action, expect);
     }
 
+    #[test]
+    fn timeline_key() {
+        let mut character = Sim2TestCharacter::new();
+        let mut weapon = NoopTimeline {};
+        let mut artifact = NoopTimeline {};
+        let cr = Sim2TestCharacter::record(Pyro);
+        let wr1 = WeaponRecord::default().name("A").atk(10.);
+        let wr2 = WeaponRecord::default().name("B").atk(20.);
+        let wr3 = WeaponRecord::default().name("C").atk_spd(10.);
+        let ar = Artifact::default();
+        let members = [TimelineMember {
+            character: &mut character,
+            weapon: &mut weapon,
+            artifact: &mut artifact,
+        }; 1];
+        let k1 = TimelineKey::new(&members, &[CharacterData::new(0, &cr, &wr1, &ar)]);
+        let k2 = TimelineKey::new(&members, &[CharacterData::new(0, &cr, &wr2, &ar)]);
+        let k3 = TimelineKey::new(&members, &[CharacterData::new(0, &cr, &wr3, &ar)]);
+        assert!(k1.is_some());
+        assert_eq!(k1, k2);
+        assert_ne!(k1, k3);
+    }
+
+    #[test]
+    fn stamina() {
+        let mut history = History::<1>::new(4.0, 0.2);
+        let mut character = Sim2TestCharacter::new().use_skill(false).use_ca(true);
+        let mut weapon = NoopTimeline {};
+        let mut artifact = NoopTimeline {};
+        let mut states = [ActionState::new(); 1];
+        let mut members = [TimelineMember {
+            character: &mut character,
+            weapon: &mut weapon,
+            artifact: &mut artifact,
+        }; 1];
+        let cr = Sim2TestCharacter::record(Pyro).weapon(WeaponType::Catalyst);
+        let wr = WeaponRecord::default();
+        let ar = Artifact::default();
+        let mut data = [CharacterData::new(0, &cr, &wr, &ar); 1];
+
+        decide_action(&mut history, &mut members, &mut states, &mut data);
+
+        // 240 stamina is enough for 5 charged attacks that cost 50 stamina,
+        // and the 6th attack waits for the recovery.
+        let n_ca = |actions: &[[CharacterAction; 1]]| actions.iter().filter(|a| a[0].is_ca()).count();
+        assert_eq!(n_ca(&history.action[..10]), 5);
+        assert_eq!(n_ca(&history.action[10..17]), 0);
+        assert_eq!(n_ca(&history.action), 6);
+        assert!(states[0].stamina < 50.);
+    }
+
+    #[test]
+    fn ayaka_dash() {
+        use crate::sim2::characters::version_2_0::Ayaka;
+        use crate::sim2::timeline::MAX_STAMINA;
+        let mut history = History::<1>::new(20.0, 0.2);
+        let mut character = Ayaka::new();
+        let mut weapon = NoopTimeline {};
+        let mut artifact = NoopTimeline {};
+        let mut states = [ActionState::new(); 1];
+        let mut members = [TimelineMember {
+            character: &mut character,
+            weapon: &mut weapon,
+            artifact: &mut artifact,
+        }; 1];
+        let cr = Ayaka::record();
+        let wr = WeaponRecord::default();
+        let ar = Artifact::default();
+        let mut data = [CharacterData::new(0, &cr, &wr, &ar); 1];
+
+        decide_action(&mut history, &mut members, &mut states, &mut data);
+
+        // Ayaka dashes after each combo, and A4 does not restore stamina
+        // beyond the maximum
+        assert!(history.action.iter().any(|a| a[0] == CharacterAction::Dash));
+        assert!(history.state.iter().all(|s| s[0].stamina <= MAX_STAMINA));
+    }
+
+    #[test]
+    fn dash_cancel() {
+        use CharacterAction::*;
+        let mut state = ActionState::new();
+        state.update1(&Na1(0.), 0.0, 0.2);
+        state.rel_time.add(0.2);
+        state.update2(&Na1(0.), 0.2, 0.2, 0.);
+        assert!(state.rel_time.na < 0.4);
+        state.update1(&Dash, 0.2, 0.2);
+        state.rel_time.add(0.2);
+        state.update2(&Dash, 0.4, 0.2, 0.);
+        // the dash cancels the NA animation but other actions wait for the dash
+        assert!(state.rel_time.na >= 0.4);
+        assert!(state.is_moving());
+        assert_eq!(state.stamina, 240. - 18.);
+        state.rel_time.add(0.2);
+        assert!(!state.is_moving());
+    }
+
     #[test] #[ignore]
     fn two_members_timeline() {
         let target = testutil::history_2at02();
@@ -417,7 +753,13 @@ mod tests {
 
         assert_eq!(history.action, target.action);
         assert_eq!(states[0].energy, 12.0);
-        assert_eq!(states[1].energy, 7.2);
+        assert_eq!(states[1].energy, 9.6);
+        // both use the burst, and the second member swaps in and out
+        let bursts = history.events.iter().filter(|e| match e.event { Event::Burst { .. } => true, _ => false }).count();
+        assert_eq!(bursts, 2);
+        assert!(history.events.iter().any(|e| e.event == Event::Swap { from: FieldCharacterIndex(1), to: FieldCharacterIndex(0) }));
+        assert!(history.events.iter().any(|e| e.event == Event::Particle { idx: FieldCharacterIndex(1), energy: 9.6 }));
+        assert!(history.events.windows(2).all(|w| w[0].time <= w[1].time));
     }
 
     #[test]
@@ -450,5 +792,39 @@ mod tests {
         assert_eq!(dmg, expect);
     }
 
+    #[test]
+    fn debuffs() {
+        use crate::sim2::record::{Debuff, DebuffStat, Stacking};
+        let mut enemy = Enemy::simple();
+        enemy.apply_debuff(0., Debuff::new("VV", DebuffStat::Res(Pyro), 40., 0., 10.));
+        // refreshed
+        enemy.apply_debuff(5., Debuff::new("VV", DebuffStat::Res(Pyro), 40., 5., 10.));
+        enemy.apply_debuff(5., Debuff::new("Lisa", DebuffStat::Def, 15., 5., 10.).stacking(Stacking::Stack));
+        enemy.apply_debuff(6., Debuff::new("Lisa", DebuffStat::Def, 15., 6., 10.).stacking(Stacking::Stack));
+        assert_eq!(enemy.debuffs.len(), 3);
+        assert_eq!(enemy.debuff(12., DebuffStat::Res(Pyro)), 40.);
+        assert_eq!(enemy.debuff(16., DebuffStat::Res(Pyro)), 0.);
+        assert_eq!(enemy.def_down(6.), 30.);
+        assert_eq!(enemy.def_down(15.5), 15.);
+        // negative resistance is halved
+        assert_eq!(enemy.resistance(12., &Pyro), 1.2);
+        // expired debuffs are removed
+        enemy.apply_debuff(20., Debuff::new("Superconduct", DebuffStat::Res(Physical), 40., 20., 12.));
+        assert_eq!(enemy.debuffs.len(), 1);
+        // debuffs that start later do not remove active ones
+        enemy.apply_debuff(25., Debuff::new("Chongyun", DebuffStat::Res(Cryo), 10., 35., 8.));
+        assert_eq!(enemy.debuffs.len(), 2);
+        assert_eq!(enemy.debuff(30., DebuffStat::Res(Physical)), 40.);
+        // the same source that starts later keeps the active one until then
+        enemy.apply_debuff(30., Debuff::new("Chongyun", DebuffStat::Res(Cryo), 10., 40., 8.));
+        assert_eq!(enemy.debuff(36., DebuffStat::Res(Cryo)), 10.);
+        assert_eq!(enemy.debuff(40., DebuffStat::Res(Cryo)), 10.);
+        assert_eq!(enemy.debuff(42., DebuffStat::Res(Cryo)), 10.);
+        assert_eq!(enemy.debuff(49., DebuffStat::Res(Cryo)), 0.);
+        // overlapping windows are not summed up
+        enemy.apply_debuff(36., Debuff::new("Chongyun", DebuffStat::Res(Cryo), 10., 36., 8.));
+        assert_eq!(enemy.debuff(40., DebuffStat::Res(Cryo)), 10.);
+    }
+
     // TODO icd
 }
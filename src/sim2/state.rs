@@ -7,6 +7,7 @@ pub struct ICDColumn<T> {
     pub skill: T,
     pub na: T,
     pub ca: T,
+    pub plunge: T,
 }
 
 impl Default for ICDColumn<f32> {
@@ -16,6 +17,7 @@ impl Default for ICDColumn<f32> {
             skill: 0.0,
             na: 0.0,
             ca: 0.0,
+            plunge: 0.0,
         }
     }
 }
@@ -27,6 +29,7 @@ impl Default for ICDColumn<u8> {
             skill: 0,
             na: 0,
             ca: 0,
+            plunge: 0,
         }
     }
 }
@@ -38,7 +41,7 @@ impl Default for ICDColumn<u8> {
 pub struct State {
     pub base_hp: f32, pub base_def: f32, pub base_atk: f32,
     pub hp: f32, pub def: f32, pub atk: f32, pub flat_hp: f32, pub flat_def: f32, pub flat_atk: f32, pub flat_dmg: f32, pub cr: f32, pub cd: f32, pub em: f32,
-    pub na_dmg: f32, pub ca_dmg: f32, pub skill_dmg: f32, pub burst_dmg: f32, pub all_dmg: f32,
+    pub na_dmg: f32, pub ca_dmg: f32, pub skill_dmg: f32, pub burst_dmg: f32, pub plunge_dmg: f32, pub all_dmg: f32,
     pub physical_dmg: f32, pub pyro_dmg: f32, pub cryo_dmg: f32, pub hydro_dmg: f32, pub electro_dmg: f32,
     pub anemo_dmg: f32, pub geo_dmg: f32, pub dendro_dmg: f32, pub elemental_dmg: f32,
     pub infusion: bool, pub stacked_buff: UnstackableBuff, pub amplifying_bonus: f32, pub transformative_bonus: f32,
@@ -51,7 +54,7 @@ impl Default for State {
         Self {
             base_hp: 0.0, base_def: 0.0, base_atk: 0.0,
             hp: 0.0, def: 0.0, atk: 0.0, flat_hp: 0.0, flat_def: 0.0, flat_atk: 0.0, flat_dmg: 0.0, cr: 0.0, cd: 0.0, em: 0.0,
-            na_dmg: 0.0, ca_dmg: 0.0, skill_dmg: 0.0, burst_dmg: 0.0, all_dmg: 0.0,
+            na_dmg: 0.0, ca_dmg: 0.0, skill_dmg: 0.0, burst_dmg: 0.0, plunge_dmg: 0.0, all_dmg: 0.0,
             physical_dmg: 0.0, pyro_dmg: 0.0, cryo_dmg: 0.0, hydro_dmg: 0.0, electro_dmg: 0.0,
             anemo_dmg: 0.0, geo_dmg: 0.0, dendro_dmg: 0.0, elemental_dmg: 0.0,
             infusion: false, stacked_buff: UnstackableBuff::new(), amplifying_bonus: 0.0, transformative_bonus: 0.0,
@@ -80,6 +83,7 @@ impl State {
         self.ca_dmg = data.weapon.ca_dmg + data.artifact.ca_dmg;
         self.skill_dmg = data.weapon.skill_dmg + data.artifact.skill_dmg;
         self.burst_dmg = data.weapon.burst_dmg + data.artifact.burst_dmg;
+        self.plunge_dmg = data.weapon.plunge_dmg + data.artifact.plunge_dmg;
         self.all_dmg = data.weapon.all_dmg + data.artifact.all_dmg;
         self.physical_dmg = data.character.physical_dmg + data.weapon.physical_dmg + data.artifact.physical_dmg;
         self.pyro_dmg = data.character.pyro_dmg + data.weapon.pyro_dmg + data.artifact.pyro_dmg;
@@ -133,6 +137,7 @@ impl State {
             DamageType::Ca => self.ca_dmg + self.all_dmg,
             DamageType::Skill => self.skill_dmg + self.all_dmg,
             DamageType::Burst => self.burst_dmg + self.all_dmg,
+            DamageType::Plunge => self.plunge_dmg + self.all_dmg,
             _ => self.all_dmg,
         }
     }
@@ -167,10 +172,43 @@ impl State {
         1.0 + cd / 100.0 * cr / 100.0
     }
 
+    // names and values of stats
+    pub fn stats(&self) -> [(&'static str, f32); 31] {
+        [
+            ("hp", self.hp), ("def", self.def), ("atk", self.atk), ("flat_hp", self.flat_hp),
+            ("flat_def", self.flat_def), ("flat_atk", self.flat_atk), ("flat_dmg", self.flat_dmg),
+            ("cr", self.cr), ("cd", self.cd), ("em", self.em), ("na_dmg", self.na_dmg),
+            ("ca_dmg", self.ca_dmg), ("skill_dmg", self.skill_dmg), ("burst_dmg", self.burst_dmg),
+            ("plunge_dmg", self.plunge_dmg), ("all_dmg", self.all_dmg), ("physical_dmg", self.physical_dmg),
+            ("pyro_dmg", self.pyro_dmg), ("cryo_dmg", self.cryo_dmg), ("hydro_dmg", self.hydro_dmg),
+            ("electro_dmg", self.electro_dmg), ("anemo_dmg", self.anemo_dmg), ("geo_dmg", self.geo_dmg),
+            ("dendro_dmg", self.dendro_dmg), ("elemental_dmg", self.elemental_dmg),
+            ("amplifying_bonus", self.amplifying_bonus), ("transformative_bonus", self.transformative_bonus),
+            ("na_talent", self.na_talent), ("ca_talent", self.ca_talent),
+            ("skill_talent", self.skill_talent), ("burst_talent", self.burst_talent),
+        ]
+    }
+
+    // stats that are different in `other`, as (name, self, other)
+    pub fn diff(&self, other: &State) -> Vec<(&'static str, f32, f32)> {
+        let mut result: Vec<(&'static str, f32, f32)> = Vec::new();
+        for ((name, a), (_, b)) in self.stats().iter().zip(other.stats().iter()) {
+            if a != b {
+                result.push((name, *a, *b));
+            }
+        }
+        if self.infusion != other.infusion {
+            result.push(("infusion", self.infusion as u8 as f32, other.infusion as u8 as f32));
+        }
+        result
+    }
+
     pub fn apply_aura(&mut self, time: f32, event: &CharacterAction) -> bool {
         use CharacterAction::*;
         match event {
-            StandStill => false,
+            StandStill |
+            Dash |
+            Jump => false,
             Burst => {
                 let b = _can_apply_aura(time, self.icd_time.burst, self.icd_count.burst);
                 _count_hit(time, &mut self.icd_time.burst, &mut self.icd_count.burst);
@@ -197,6 +235,11 @@ impl State {
                 _count_hit(time, &mut self.icd_time.ca, &mut self.icd_count.ca);
                 b
             },
+            Plunge => {
+                let b = _can_apply_aura(time, self.icd_time.plunge, self.icd_count.plunge);
+                _count_hit(time, &mut self.icd_time.plunge, &mut self.icd_count.plunge);
+                b
+            },
         }
     }
 }
@@ -0,0 +1,218 @@
+use crate::sim2::timeline::ActionState;
+use crate::sim2::attack::DamageResult;
+use crate::sim2::types::CharacterAction;
+use crate::sim2::record::CharacterData;
+use crate::sim2::trace::Tracer;
+
+// cooldowns longer than this are regarded as the same, e.g. skills that were
+// never used
+const MAX_COOLDOWN: f32 = 60.;
+
+// energy that may differ between the same states of a cycle, because
+// particles are scaled by ER
+const ENERGY_TOLERANCE: f32 = 2.;
+
+// energy and the time since the last skill and burst of a member
+#[derive(Debug, Copy, Clone, PartialEq)]
+struct Snapshot {
+    energy: f32,
+    skill: f32,
+    burst: f32,
+}
+
+impl Snapshot {
+    fn new(state: &ActionState) -> Self {
+        Self {
+            energy: state.energy,
+            skill: state.rel_time.press.min(state.rel_time.hold).min(MAX_COOLDOWN),
+            burst: state.rel_time.burst.min(MAX_COOLDOWN),
+        }
+    }
+
+    fn is_similar(&self, other: &Self, unit_time: f32) -> bool {
+        (self.energy - other.energy).abs() <= ENERGY_TOLERANCE
+            && (self.skill - other.skill).abs() < 0.5 * unit_time
+            && (self.burst - other.burst).abs() < 0.5 * unit_time
+    }
+}
+
+// a member at each tick
+#[derive(Debug)]
+struct Lane {
+    name: &'static str,
+    ticks: Vec<(f32, CharacterAction, Snapshot)>,
+}
+
+// rotations of the steady state
+#[derive(Debug)]
+pub struct Cycle {
+    pub start: f32,
+    pub end: f32,
+    // the number of rotations between `start` and `end`
+    pub rotations: usize,
+    // whether energy and cooldowns of all members at `end` are the same as
+    // those at `start`. otherwise only the rotation length of the member who
+    // uses bursts most often repeats, and damage is averaged over rotations.
+    pub exact: bool,
+    pub damage: f32,
+    // damage of each member between `start` and `end`
+    pub members: Vec<(&'static str, f32)>,
+}
+
+impl Cycle {
+    // the length of one rotation
+    pub fn length(&self) -> f32 {
+        (self.end - self.start) / self.rotations as f32
+    }
+
+    pub fn dps(&self) -> f32 {
+        self.damage / (self.end - self.start)
+    }
+}
+
+// Collects energy and cooldowns of members, and finds rotations that repeat
+// after the first ones which start with full energy and all cooldowns ready.
+// A rotation is the time between two bursts of the member who uses bursts
+// most often. The steady state begins at the first burst where energy and
+// cooldowns of all members are the same as those at a later burst. If there
+// is no such burst, e.g. because the bursts of the other members are not in
+// phase, it begins when the rotation length stops changing.
+#[derive(Debug)]
+pub struct SteadyState {
+    pub unit_time: f32,
+    lanes: Vec<Lane>,
+    damage: Vec<(f32, &'static str, f32)>,
+}
+
+impl SteadyState {
+    pub fn new(unit_time: f32) -> Self {
+        Self {
+            unit_time,
+            lanes: Vec::new(),
+            damage: Vec::new(),
+        }
+    }
+
+    fn bursts(lane: &Lane) -> Vec<usize> {
+        lane.ticks.iter().enumerate()
+            .filter(|(_, (_, a, _))| *a == CharacterAction::Burst)
+            .map(|(i, _)| i)
+            .collect()
+    }
+
+    pub fn cycle(&self) -> Option<Cycle> {
+        // the first member of those who use bursts most often
+        let anchor = self.lanes.iter().rev().max_by_key(|l| Self::bursts(l).len())?;
+        let bursts = Self::bursts(anchor);
+        let time = |i: usize| anchor.ticks[i].0;
+        // the shortest period that ends at the earliest burst
+        for (j, end) in bursts.iter().enumerate() {
+            for (i, start) in bursts[..j].iter().enumerate().rev() {
+                if self.lanes.iter().all(|l| l.ticks[*start].2.is_similar(&l.ticks[*end].2, self.unit_time)) {
+                    return Some(self.collect(time(*start), time(*end), j - i, true));
+                }
+            }
+        }
+        // all rotations after the last change of the length
+        let lengths: Vec<f32> = bursts.windows(2).map(|w| time(w[1]) - time(w[0])).collect();
+        let last = *lengths.last()?;
+        let n = lengths.iter().rev().take_while(|x| (*x - last).abs() < 0.5 * self.unit_time).count();
+        if n < 2 {
+            return None;
+        }
+        Some(self.collect(time(bursts[bursts.len() - 1 - n]), time(bursts[bursts.len() - 1]), n, false))
+    }
+
+    fn collect(&self, start: f32, end: f32, rotations: usize, exact: bool) -> Cycle {
+        let mut members: Vec<(&'static str, f32)> = self.lanes.iter().map(|l| (l.name, 0.)).collect();
+        for (time, name, damage) in self.damage.iter() {
+            if start <= *time && *time < end {
+                if let Some(m) = members.iter_mut().find(|(n, _)| n == name) {
+                    m.1 += damage;
+                }
+            }
+        }
+        Cycle {
+            start,
+            end,
+            rotations,
+            exact,
+            damage: members.iter().map(|(_, d)| d).sum(),
+            members,
+        }
+    }
+}
+
+impl Tracer for SteadyState {
+    fn action(&mut self, data: &CharacterData, action: &CharacterAction, state: &ActionState) -> () {
+        let idx = data.idx.0;
+        while self.lanes.len() <= idx {
+            self.lanes.push(Lane {
+                name: "",
+                ticks: Vec::new(),
+            });
+        }
+        let lane = &mut self.lanes[idx];
+        lane.name = data.character.name;
+        lane.ticks.push((state.current_time, *action, Snapshot::new(state)));
+    }
+
+    fn damage(&mut self, dmg: &DamageResult) -> () {
+        self.damage.push((dmg.time, dmg.name, dmg.damage + dmg.reaction));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sim2::party::{Party, PartyMember, SimulationConfig};
+    use crate::sim2::record::Enemy;
+    use crate::sim2::attack::DamageResultUtil;
+
+    fn run(party: &mut Party, simulation_time: f32) -> (Option<Cycle>, f32) {
+        let mut config = SimulationConfig::default();
+        config.simulation_time = simulation_time;
+        let mut steady = SteadyState::new(config.unit_time);
+        let total = party.run_with(&config, &mut Enemy::hilichurl(), &mut steady).unwrap().total_damage();
+        (steady.cycle(), total)
+    }
+
+    #[test]
+    fn solo() {
+        let mut party = Party::new()
+            .member(PartyMember::find("Diluc", "Prototype Archaic", "Gladiator's Finale").unwrap());
+        let (cycle, _) = run(&mut party, 60.);
+        let cycle = cycle.unwrap();
+        // the state after the first burst repeats
+        assert!(cycle.exact);
+        assert_eq!(cycle.rotations, 1);
+        assert!(cycle.start < 1.);
+        assert!(cycle.length() > 30.);
+        assert_eq!(cycle.members[0].1, cycle.damage);
+    }
+
+    #[test]
+    fn diluc_bennett() {
+        let mut party = Party::new()
+            .member(PartyMember::find("Diluc", "Prototype Archaic", "Gladiator's Finale").unwrap())
+            .member(PartyMember::find("Bennett", "Prototype Rancour", "Noblesse Oblige").unwrap());
+        // bursts of Bennett are not in phase with those of Diluc
+        let (cycle, _) = run(&mut party, 60.);
+        assert!(cycle.is_none());
+        let (cycle, total) = run(&mut party, 200.);
+        let cycle = cycle.unwrap();
+        assert!(!cycle.exact);
+        assert!(cycle.rotations >= 2);
+        assert!((cycle.length() - 31.8).abs() < 0.2);
+        assert_eq!(cycle.members.len(), 2);
+        assert!(cycle.damage < total);
+        // the first rotation with full energy is excluded
+        assert!(cycle.dps() < total / 200.);
+    }
+
+    #[test]
+    fn no_burst() {
+        let steady = SteadyState::new(0.2);
+        assert!(steady.cycle().is_none());
+    }
+}
@@ -0,0 +1,81 @@
+// Enumerates teams of `size` out of `n` characters as an on-field member and
+// an unordered set of supporters, because supporters are interchangeable:
+// teams of 3 out of [0, 1, 2] are (0, [1, 2]), (1, [0, 2]) and (2, [0, 1]).
+#[derive(Debug)]
+pub struct Teams {
+    n: usize,
+    field: usize,
+    // indices into the characters other than `field`
+    others: Vec<usize>,
+    finished: bool,
+}
+
+impl Teams {
+    pub fn new(n: usize, size: usize) -> Self {
+        Self {
+            n,
+            field: 0,
+            others: (0..size.max(1) - 1).collect(),
+            finished: size == 0 || size > n,
+        }
+    }
+
+    fn supporters(&self) -> Vec<usize> {
+        self.others.iter().map(|&i| if i < self.field { i } else { i + 1 }).collect()
+    }
+
+    // advance `others` to the next combination
+    fn advance(&mut self) -> bool {
+        let m = self.others.len();
+        let n = self.n - 1;
+        for i in (0..m).rev() {
+            if self.others[i] < n - m + i {
+                self.others[i] += 1;
+                for j in i + 1..m {
+                    self.others[j] = self.others[j - 1] + 1;
+                }
+                return true;
+            }
+        }
+        false
+    }
+}
+
+impl Iterator for Teams {
+    type Item = (usize, Vec<usize>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None
+        }
+        let item = (self.field, self.supporters());
+        if !self.advance() {
+            self.field += 1;
+            let m = self.others.len();
+            self.others = (0..m).collect();
+            self.finished = self.field == self.n;
+        }
+        Some(item)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn teams() {
+        let teams: Vec<(usize, Vec<usize>)> = Teams::new(3, 3).collect();
+        assert_eq!(teams, vec![(0, vec![1, 2]), (1, vec![0, 2]), (2, vec![0, 1])]);
+        let teams: Vec<(usize, Vec<usize>)> = Teams::new(3, 1).collect();
+        assert_eq!(teams, vec![(0, vec![]), (1, vec![]), (2, vec![])]);
+        // 5 on-field members and 6 pairs of supporters out of the other 4
+        let teams: Vec<(usize, Vec<usize>)> = Teams::new(5, 3).collect();
+        assert_eq!(teams.len(), 30);
+        for (field, supporters) in teams.iter() {
+            assert!(!supporters.contains(field));
+            assert!(supporters[0] < supporters[1]);
+        }
+        assert_eq!(Teams::new(2, 3).count(), 0);
+    }
+}
@@ -8,6 +8,9 @@ pub struct ActionColumn<T> {
     pub hold: T,
     pub na: T,
     pub ca: T,
+    pub plunge: T,
+    pub dash: T,
+    pub jump: T,
 }
 
 impl Default for ActionColumn<f32> {
@@ -18,6 +21,9 @@ impl Default for ActionColumn<f32> {
             hold: 0.0,
             na: 0.0,
             ca: 0.0,
+            plunge: 0.0,
+            dash: 0.0,
+            jump: 0.0,
         }
     }
 }
@@ -29,6 +35,9 @@ impl ActionColumn<f32> {
         self.hold += x;
         self.na += x;
         self.ca += x;
+        self.plunge += x;
+        self.dash += x;
+        self.jump += x;
     }
 
     pub fn copy(&mut self, other: &Self) -> () {
@@ -37,6 +46,9 @@ impl ActionColumn<f32> {
         self.hold = other.hold;
         self.na = other.na;
         self.ca = other.ca;
+        self.plunge = other.plunge;
+        self.dash = other.dash;
+        self.jump = other.jump;
     }
 }
 
@@ -57,8 +69,22 @@ pub struct ActionState {
     pub reduce_skill: f32,
     pub energy: f32,
     pub er: f32,
+
+    // stamina of this member. `ca_stamina` is the cost of a charged attack and
+    // `reduce_stamina` is the percentage of the cost that is reduced.
+    pub stamina: f32,
+    pub ca_stamina: f32,
+    pub reduce_stamina: f32,
 }
 
+pub const MAX_STAMINA: f32 = 240.;
+pub const STAMINA_RECOVERY: f32 = 25.;
+pub const DASH_STAMINA: f32 = 18.;
+
+// frames of movements. other actions cannot be performed until they end.
+pub const DASH_TIME: f32 = 0.35;
+pub const JUMP_TIME: f32 = 0.55;
+
 // 1. decide an action
 // 2. modify states
 // 3. generate energy
@@ -73,6 +99,9 @@ impl ActionState {
             reduce_skill: 0.0,
             energy: 0.0,
             er: 0.0,
+            stamina: MAX_STAMINA,
+            ca_stamina: 0.0,
+            reduce_stamina: 0.0,
         };
         x.abs_time.add(-1.);
         x.rel_time.add(100.);
@@ -96,6 +125,36 @@ impl ActionState {
         }
     }
 
+    pub fn stamina_cost(&self, event: &CharacterAction) -> f32 {
+        let cost = match event {
+            CharacterAction::Ca(_) => self.ca_stamina,
+            CharacterAction::Dash => DASH_STAMINA,
+            _ => return 0.,
+        };
+        let r = 1.0 - self.reduce_stamina / 100.0;
+        if r > 0. {
+            cost * r
+        } else {
+            0.
+        }
+    }
+
+    // charged attacks and dashes cannot be performed without enough stamina
+    pub fn has_stamina(&self, event: &CharacterAction) -> bool {
+        self.stamina >= self.stamina_cost(event)
+    }
+
+    // Characters check this in `decide_action` before they change their own
+    // states for a charged attack, e.g. Explosive Spark of Klee.
+    pub fn can_ca(&self) -> bool {
+        self.has_stamina(&CharacterAction::Ca(0.))
+    }
+
+    // whether the member is still dashing or jumping
+    pub fn is_moving(&self) -> bool {
+        self.rel_time.dash < DASH_TIME || self.rel_time.jump < JUMP_TIME
+    }
+
     // any action other than NA resets rel_time.na
     pub fn update1(&mut self, event: &CharacterAction, current_time: f32, elapsed_time: f32) -> () {
         self.current_time = current_time;
@@ -131,12 +190,40 @@ impl ActionState {
                 self.rel_time.ca = *carryover;
                 self.rel_time.na = 100.;
             },
+            CharacterAction::Plunge => {
+                self.abs_time.plunge = current_time;
+                self.rel_time.plunge = 0.;
+                self.rel_time.na = 100.;
+            },
+            // movements cancel NA and CA so that they can be performed at
+            // the next action
+            CharacterAction::Dash => {
+                self.abs_time.dash = current_time;
+                self.rel_time.dash = 0.;
+                self.rel_time.na = 100.;
+                self.rel_time.ca = 100.;
+            },
+            CharacterAction::Jump => {
+                self.abs_time.jump = current_time;
+                self.rel_time.jump = 0.;
+                self.rel_time.na = 100.;
+                self.rel_time.ca = 100.;
+            },
         }
         // self.rel_time.add(elapsed_time);
     }
 
     pub fn update2(&mut self, event: &CharacterAction, current_time: f32, elapsed_time: f32, energy: f32) -> () {
         self.energy += energy;
+        let cost = self.stamina_cost(event);
+        if cost > 0. {
+            self.stamina -= cost;
+        } else if self.stamina < MAX_STAMINA {
+            self.stamina += STAMINA_RECOVERY * elapsed_time;
+            if self.stamina > MAX_STAMINA {
+                self.stamina = MAX_STAMINA;
+            }
+        }
         if self.reduce_skill != 0. {
             self.rel_time.press += self.reduce_skill;
             self.rel_time.hold += self.reduce_skill;
@@ -155,12 +242,17 @@ impl ActionState {
         self.reduce_skill = other.reduce_skill;
         self.energy = other.energy;
         self.er = other.er;
+        self.stamina = other.stamina;
+        self.ca_stamina = other.ca_stamina;
+        self.reduce_stamina = other.reduce_stamina;
     }
 
     pub fn init(&mut self, data: &CharacterData) -> () {
         self.atk_spd = data.weapon.atk_spd + data.artifact.atk_spd;
         self.reduce_skill = 0.0;
         self.er = data.character.er + data.weapon.er + data.artifact.er;
+        self.ca_stamina = data.character.weapon.ca_stamina();
+        self.reduce_stamina = 0.0;
     }
 
     pub fn er(&self) -> f32 {
@@ -174,6 +266,8 @@ impl ActionState {
             DamageType::Skill
         } else if self.did_ca() {
             DamageType::Ca
+        } else if self.did_plunge() {
+            DamageType::Plunge
         } else if self.did_na() {
             DamageType::Na
         } else {
@@ -204,6 +298,10 @@ impl ActionState {
     pub fn did_ca(&self) -> bool {
         self.abs_time.ca == self.current_time
     }
+
+    pub fn did_plunge(&self) -> bool {
+        self.abs_time.plunge == self.current_time
+    }
 }
 
 // can be used to implement characters, weapons and artifacts
@@ -214,5 +312,10 @@ pub trait Timeline {
     // generate energy and modify acceleration states according to the event
     fn accelerate(&mut self, field_energy: &mut Vec<FieldEnergy>, event: &CharacterAction, state: &mut ActionState, data: &CharacterData) -> () {}
 
+    // Weapons and artifacts that implement `accelerate` should return true,
+    // otherwise the timeline is reused for other weapons and artifacts (see
+    // `TimelineKey`).
+    fn accelerates(&self) -> bool { false }
+
     fn reset_timeline(&mut self) -> () {}
 }
@@ -0,0 +1,152 @@
+use std::fmt;
+use std::fmt::Write;
+
+use crate::sim2::state::State;
+use crate::sim2::timeline::ActionState;
+use crate::sim2::attack::{Attack, DamageResult};
+use crate::sim2::types::CharacterAction;
+use crate::sim2::record::{CharacterData, Debuff};
+use crate::sim2::element::ElementalReactionType;
+use crate::sim2::event::EnergyGain;
+
+// receives events of `simulate::calculate_damage_with`
+pub trait Tracer {
+    // an action of a member at each tick, and the state after the action
+    fn action(&mut self, _data: &CharacterData, _action: &CharacterAction, _state: &ActionState) -> () {}
+
+    // attacks queued by a character, weapon or artifact named `source`
+    fn queue(&mut self, _time: f32, _source: &'static str, _attacks: &[Attack]) -> () {}
+
+    // `modify` of `source` was called for `attack`
+    fn modify(&mut self, _source: &'static str, _attack: &Attack, _before: &State, _after: &State) -> () {}
+
+    // elements of `attack` reacted with the aura of the enemy
+    fn reaction(&mut self, _attack: &Attack, _reaction: &ElementalReactionType) -> () {}
+
+    fn damage(&mut self, _dmg: &DamageResult) -> () {}
+
+    // a debuff was applied to the enemy
+    fn debuff(&mut self, _debuff: &Debuff) -> () {}
+
+    // a member received energy. energy is reported after all actions.
+    fn energy(&mut self, _gain: &EnergyGain) -> () {}
+}
+
+pub struct NoTrace;
+
+impl Tracer for NoTrace {}
+
+// a human readable trace that is ordered by time
+#[derive(Debug)]
+pub struct TextTrace {
+    // skip ticks of members who stand still
+    pub skip_standstill: bool,
+    lines: Vec<(f32, String)>,
+}
+
+impl TextTrace {
+    pub fn new() -> Self {
+        Self {
+            skip_standstill: false,
+            lines: Vec::new(),
+        }
+    }
+
+    pub fn skip_standstill(mut self, skip: bool) -> Self { self.skip_standstill = skip; self }
+
+    fn push(&mut self, time: f32, line: String) -> () {
+        self.lines.push((time, line));
+    }
+
+    fn attack_text(attack: &Attack) -> String {
+        format!("{:?} {:.1}% {:?} {:.1}U{} member {}", attack.kind, attack.multiplier, attack.element.aura, attack.element.unit,
+            if attack.aura_application { "" } else { " (no aura)" }, attack.idx.0)
+    }
+}
+
+impl Tracer for TextTrace {
+    fn action(&mut self, data: &CharacterData, action: &CharacterAction, state: &ActionState) -> () {
+        if self.skip_standstill && *action == CharacterAction::StandStill {
+            return;
+        }
+        let line = format!("{:<14} {:<12} energy {:>5.1}/{:<3} since skill {:>5.1}s burst {:>5.1}s stamina {:>5.1}",
+            data.character.name, format!("{:?}", action), state.energy, data.character.energy_cost,
+            state.rel_time.press.min(state.rel_time.hold).min(99.9), state.rel_time.burst.min(99.9), state.stamina);
+        self.push(state.current_time, line);
+    }
+
+    fn queue(&mut self, time: f32, source: &'static str, attacks: &[Attack]) -> () {
+        for a in attacks.iter() {
+            let line = format!("  queue   {}: {} at {:.2}s", source, Self::attack_text(a), a.time);
+            self.push(time, line);
+        }
+    }
+
+    fn modify(&mut self, source: &'static str, attack: &Attack, before: &State, after: &State) -> () {
+        let diff = before.diff(after);
+        if diff.is_empty() {
+            return;
+        }
+        let mut line = format!("  modify  {} on {:?} of member {}:", source, attack.kind, attack.idx.0);
+        for (name, a, b) in diff.iter() {
+            write!(line, " {} {:.1} -> {:.1}", name, a, b).unwrap();
+        }
+        self.push(attack.time, line);
+    }
+
+    fn reaction(&mut self, attack: &Attack, reaction: &ElementalReactionType) -> () {
+        let line = format!("  react   {} by {:?} of member {}", reaction.name(), attack.kind, attack.idx.0);
+        self.push(attack.time, line);
+    }
+
+    fn damage(&mut self, dmg: &DamageResult) -> () {
+        let line = format!("  damage  {} {:?} {:.0} (reaction {:.0})", dmg.name, dmg.kind, dmg.damage, dmg.reaction);
+        self.push(dmg.time, line);
+    }
+
+    fn debuff(&mut self, debuff: &Debuff) -> () {
+        let line = format!("  debuff  {}: {:?} -{:.0}% until {:.2}s", debuff.source, debuff.stat, debuff.value, debuff.end());
+        self.push(debuff.start, line);
+    }
+}
+
+impl fmt::Display for TextTrace {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut lines: Vec<&(f32, String)> = self.lines.iter().collect();
+        // stable, so events at the same time keep their order
+        lines.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+        for (time, line) in lines {
+            writeln!(f, "[{:>6.2}] {}", time, line)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sim2::party::{Party, PartyMember, SimulationConfig};
+    use crate::sim2::record::Enemy;
+
+    #[test]
+    fn text_trace() {
+        let mut config = SimulationConfig::default();
+        config.simulation_time = 4.0;
+        let mut party = Party::new()
+            .member(PartyMember::find("Diluc", "Prototype Archaic", "Gladiator's Finale").unwrap())
+            .member(PartyMember::find("Bennett", "Prototype Rancour", "Noblesse Oblige").unwrap());
+        let mut tracer = TextTrace::new();
+        let dmg = party.run_with(&config, &mut Enemy::hilichurl(), &mut tracer).unwrap();
+        let trace = tracer.to_string();
+        assert!(trace.contains("Diluc"));
+        assert!(trace.contains("queue"));
+        assert_eq!(trace.matches("  damage ").count(), dmg.len());
+        // Bennett's burst buffs ATK of Diluc
+        assert!(trace.contains("modify  Bennett on"));
+        let mut party = Party::new()
+            .member(PartyMember::find("Diluc", "Prototype Archaic", "Gladiator's Finale").unwrap());
+        let mut tracer = TextTrace::new().skip_standstill(true);
+        party.run_with(&config, &mut Enemy::hilichurl(), &mut tracer).unwrap();
+        assert!(!tracer.to_string().contains("StandStill"));
+    }
+}
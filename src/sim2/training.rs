@@ -1,8 +1,8 @@
 use crate::sim2::state::State;
 use crate::sim2::timeline::{Timeline};
 use crate::sim2::attack::{WeaponAttack};
-use crate::sim2::types::{WeaponType, SCORE};
-use crate::sim2::record::{WeaponRecord, Artifact};
+use crate::sim2::types::{WeaponType};
+use crate::sim2::record::{WeaponRecord, Artifact, SubstatRolls};
 
 use WeaponType::*;
 
@@ -187,9 +187,7 @@ impl TrainingArtifact0 {
         Artifact::default()
             .name("Atk70 Em280 Er78").version(1.0).preference(&[])
             .elemental_dmg(15.).physical_dmg(25.).cr(20.)
-            .atk(SCORE.atk(33.3333))
-            .em(SCORE.em(33.3333))
-            .er(SCORE.er(33.3333))
+            .substats(SubstatRolls::new().atk(12.).em(12.).er(12.))
     }
 }
 
@@ -200,10 +198,9 @@ impl WeaponAttack for TrainingArtifact1 {}
 impl TrainingArtifact1 {
     pub fn record() -> Artifact {
         Artifact::default()
-            .name("Atk168 Em167").version(1.0).preference(&[])
+            .name("Atk169 Em163").version(1.0).preference(&[])
             .elemental_dmg(15.).physical_dmg(25.).cr(20.)
-            .atk(SCORE.atk(80.))
-            .em(SCORE.em(20.))
+            .substats(SubstatRolls::new().atk(28.8).em(7.2))
     }
 }
 
@@ -214,10 +211,9 @@ impl WeaponAttack for TrainingArtifact2 {}
 impl TrainingArtifact2 {
     pub fn record() -> Artifact {
         Artifact::default()
-            .name("Atk168 Er46").version(1.0).preference(&[])
+            .name("Atk169 Er45").version(1.0).preference(&[])
             .elemental_dmg(15.).physical_dmg(25.).cr(20.)
-            .atk(SCORE.atk(80.))
-            .er(SCORE.er(20.))
+            .substats(SubstatRolls::new().atk(28.8).er(7.2))
     }
 }
 
@@ -228,10 +224,9 @@ impl WeaponAttack for TrainingArtifact3 {}
 impl TrainingArtifact3 {
     pub fn record() -> Artifact {
         Artifact::default()
-            .name("Atk41 Em671").version(1.0).preference(&[])
+            .name("Atk41 Em676").version(1.0).preference(&[])
             .elemental_dmg(15.).physical_dmg(25.).cr(20.)
-            .atk(SCORE.atk(20.))
-            .em(SCORE.em(80.))
+            .substats(SubstatRolls::new().atk(7.2).em(28.8))
     }
 }
 
@@ -242,10 +237,9 @@ impl WeaponAttack for TrainingArtifact4 {}
 impl TrainingArtifact4 {
     pub fn record() -> Artifact {
         Artifact::default()
-            .name("Em671 Er46").version(1.0).preference(&[])
+            .name("Em676 Er45").version(1.0).preference(&[])
             .elemental_dmg(15.).physical_dmg(25.).cr(20.)
-            .em(SCORE.em(80.))
-            .er(SCORE.er(20.))
+            .substats(SubstatRolls::new().em(28.8).er(7.2))
     }
 }
 
@@ -256,10 +250,9 @@ impl WeaponAttack for TrainingArtifact5 {}
 impl TrainingArtifact5 {
     pub fn record() -> Artifact {
         Artifact::default()
-            .name("Atk42 Er186").version(1.0).preference(&[])
+            .name("Atk41 Er188").version(1.0).preference(&[])
             .elemental_dmg(15.).physical_dmg(25.).cr(20.)
-            .atk(SCORE.atk(20.))
-            .er(SCORE.er(80.))
+            .substats(SubstatRolls::new().atk(7.2).er(28.8))
     }
 }
 
@@ -270,10 +263,9 @@ impl WeaponAttack for TrainingArtifact6 {}
 impl TrainingArtifact6 {
     pub fn record() -> Artifact {
         Artifact::default()
-            .name("Em167 Er186").version(1.0).preference(&[])
+            .name("Em163 Er188").version(1.0).preference(&[])
             .elemental_dmg(15.).physical_dmg(25.).cr(20.)
-            .em(SCORE.em(20.))
-            .er(SCORE.er(80.))
+            .substats(SubstatRolls::new().em(7.2).er(28.8))
     }
 }
 
@@ -286,8 +278,7 @@ impl TrainingArtifact7 {
         Artifact::default()
             .name("Atk105 Em420").version(1.0).preference(&[])
             .elemental_dmg(15.).physical_dmg(25.).cr(20.)
-            .atk(SCORE.atk(50.))
-            .em(SCORE.em(50.))
+            .substats(SubstatRolls::new().atk(18.).em(18.))
     }
 }
 
@@ -298,10 +289,9 @@ impl WeaponAttack for TrainingArtifact8 {}
 impl TrainingArtifact8 {
     pub fn record() -> Artifact {
         Artifact::default()
-            .name("Atk105 Er116").version(1.0).preference(&[])
+            .name("Atk105 Er117").version(1.0).preference(&[])
             .elemental_dmg(15.).physical_dmg(25.).cr(20.)
-            .atk(SCORE.atk(50.))
-            .er(SCORE.er(50.))
+            .substats(SubstatRolls::new().atk(18.).er(18.))
     }
 }
 
@@ -312,10 +302,9 @@ impl WeaponAttack for TrainingArtifact9 {}
 impl TrainingArtifact9 {
     pub fn record() -> Artifact {
         Artifact::default()
-            .name("Em420 Er116").version(1.0).preference(&[])
+            .name("Em420 Er117").version(1.0).preference(&[])
             .elemental_dmg(15.).physical_dmg(25.).cr(20.)
-            .em(SCORE.em(50.))
-            .er(SCORE.er(50.))
+            .substats(SubstatRolls::new().em(18.).er(18.))
     }
 }
 
@@ -328,7 +317,7 @@ impl TrainingArtifact10 {
         Artifact::default()
             .name("Atk210").version(1.0).preference(&[])
             .elemental_dmg(15.).physical_dmg(25.).cr(20.)
-            .atk(SCORE.atk(100.))
+            .substats(SubstatRolls::new().atk(36.))
     }
 }
 
@@ -341,7 +330,7 @@ impl TrainingArtifact11 {
         Artifact::default()
             .name("Em839").version(1.0).preference(&[])
             .elemental_dmg(15.).physical_dmg(25.).cr(20.)
-            .em(SCORE.em(100.))
+            .substats(SubstatRolls::new().em(36.))
     }
 }
 
@@ -354,6 +343,6 @@ impl TrainingArtifact12 {
         Artifact::default()
             .name("Er233").version(1.0).preference(&[])
             .elemental_dmg(15.).physical_dmg(25.).cr(20.)
-            .er(SCORE.er(100.))
+            .substats(SubstatRolls::new().er(36.))
     }
 }
@@ -1,8 +1,8 @@
 use std::cmp::PartialEq;
 
 use crate::sim2::attack::Attack;
-    use crate::sim2::cli::Args;
 use crate::sim2::record::{CharacterRecord, WeaponRecord, Artifact};
+use crate::sim2::util::Rng;
 use crate::sim2::element::{ElementalGauge, ElementalReactionType, ElementalReaction, PYRO_GAUGE1A, CRYO_GAUGE1A, HYDRO_GAUGE1A, ELECTRO_GAUGE1A, ANEMO_GAUGE1A, GEO_GAUGE1A, DENDRO_GAUGE1A, PHYSICAL_GAUGE,};
 
 // use self::AttackType::*;
@@ -38,12 +38,28 @@ pub enum WeaponType {
     Catalyst,
 }
 
+impl WeaponType {
+    // stamina consumed by one `CharacterAction::Ca`. Aimed shots of bows do
+    // not consume stamina. Claymores consume stamina per second of spinning,
+    // and a `Ca` of claymores is regarded as one second of the spin.
+    pub fn ca_stamina(&self) -> f32 {
+        match self {
+            WeaponType::Sword => 20.,
+            WeaponType::Claymore => 40.,
+            WeaponType::Polearm => 25.,
+            WeaponType::Bow => 0.,
+            WeaponType::Catalyst => 50.,
+        }
+    }
+}
+
 #[derive(Debug, PartialEq, Copy, Clone)]
 pub enum DamageType {
     Na,
     Ca,
     Skill,
     Burst,
+    Plunge,
     AdditionalAttack,
     FlatDMG, // Ocean-Hued Clam
 }
@@ -58,10 +74,13 @@ pub enum DamageType {
 pub enum CharacterAction {
     Na1(f32), Na2(f32), Na3(f32), Na4(f32), Na5(f32), Na6(f32),
     Ca(f32),
-    // Plunge,
+    Plunge,
     PressSkill,
     HoldSkill,
     Burst,
+    // movements cancel the animation of NA and CA
+    Dash,
+    Jump,
     StandStill,
 }
 
@@ -123,6 +142,7 @@ pub trait VecFieldEnergy {
     fn has_particles(&self) -> bool;
     fn push_p(&mut self, p: Particle) -> ();
     fn push_e(&mut self, e: f32) -> ();
+    fn push_e_to(&mut self, e: f32, recipient: Recipient) -> ();
 }
 
 impl VecFieldEnergy for Vec<FieldEnergy> {
@@ -141,7 +161,11 @@ impl VecFieldEnergy for Vec<FieldEnergy> {
     }
 
     fn push_e(&mut self, e: f32) -> () {
-        self.push(FieldEnergy::Energy(e));
+        self.push(FieldEnergy::Energy(e, Recipient::All));
+    }
+
+    fn push_e_to(&mut self, e: f32, recipient: Recipient) -> () {
+        self.push(FieldEnergy::Energy(e, recipient));
     }
 }
 
@@ -158,18 +182,56 @@ impl VecFieldEnergy for [FieldEnergy] {
 
     fn push_p(&mut self, _p: Particle) -> () {}
     fn push_e(&mut self, _e: f32) -> () {}
+    fn push_e_to(&mut self, _e: f32, _recipient: Recipient) -> () {}
 }
 
 #[derive(Debug)]
 pub enum FieldEnergy {
-    Energy(f32),
+    // flat energy, which is not affected by the party size
+    Energy(f32, Recipient),
     Particle(Particle),
 }
 
+// members who receive flat energy
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub enum Recipient {
+    // the member who generated the energy
+    Oneself,
+    // all members except the one who generated the energy
+    Others,
+    All,
+    // the member on the field
+    OnField,
+    // the member who generated the energy and members of the element that it
+    // absorbs. it is the element of the first member whose element can be
+    // absorbed (see `Enemy::absorb_element`).
+    Absorbed,
+}
+
+impl Recipient {
+    // `visions` are the visions of the members in the party order
+    pub fn includes(&self, member: usize, generator: usize, visions: &[Vision]) -> bool {
+        match self {
+            Recipient::Oneself => member == generator,
+            Recipient::Others => member != generator,
+            Recipient::All => true,
+            Recipient::OnField => FieldCharacterIndex(member).is_on_field(),
+            Recipient::Absorbed => {
+                let absorbed = visions.iter().find(|v| matches!(v, Pyro | Hydro | Electro | Cryo));
+                member == generator || absorbed == Some(&visions[member])
+            },
+        }
+    }
+}
+
 #[derive(Debug, PartialEq, Copy, Clone)]
 pub struct Particle {
     element: Vision,
+    // the expected number of particles
     n: f32,
+    // numbers of particles and their probabilities. empty if the number is
+    // only known as `n`, in which case the integers around `n` are sampled.
+    chance: &'static [(f32, f32)],
 }
 
 impl Particle {
@@ -177,17 +239,54 @@ impl Particle {
         Self {
             element,
             n,
+            chance: &[],
         }
     }
 
     pub fn neutral(n: f32) -> Self {
         Self {
             element: Physical,
+            n,
+            chance: &[],
+        }
+    }
+
+    // e.g. `&[(2., 0.5), (3., 0.5)]` for 2 or 3 particles with the same
+    // probability
+    pub fn chance(element: Vision, chance: &'static [(f32, f32)]) -> Self {
+        Self {
+            element,
+            n: chance.iter().map(|(n, p)| n * p).sum(),
+            chance,
+        }
+    }
+
+    // particles of a number drawn from the distribution
+    pub fn sample(&self, rng: &mut Rng) -> Self {
+        let x = rng.chance();
+        let n = if self.chance.is_empty() {
+            let floor = self.n.floor();
+            if x < self.n - floor { floor + 1. } else { floor }
+        } else {
+            let mut acc = 0.;
+            let mut n = self.chance[self.chance.len() - 1].0;
+            for (k, p) in self.chance.iter() {
+                acc += p;
+                if x < acc {
+                    n = *k;
+                    break;
+                }
+            }
             n
+        };
+        Self {
+            element: self.element,
+            n,
+            chance: &[],
         }
     }
 
-    // do not consider the number of members
+    // energy of the member on the field when particles land
     pub fn on_field_energy(&self, reciver_element: &Vision) -> f32 {
         self.n * if self.element == *reciver_element {
             3.0
@@ -199,17 +298,31 @@ impl Particle {
         }
     }
 
-    pub fn off_field_energy(&self, reciver_element: &Vision) -> f32 {
-        self.n * if self.element == *reciver_element {
-            1.8
-        } else if self.element == Physical {
-            1.2
-        } else {
-            0.6
+    // energy of the other members in a party of `members`
+    pub fn off_field_energy(&self, reciver_element: &Vision, members: usize) -> f32 {
+        self.on_field_energy(reciver_element) * Self::off_field_rate(members)
+    }
+
+    // off-field members receive less energy in larger parties
+    pub fn off_field_rate(members: usize) -> f32 {
+        match members {
+            0 | 1 => 1.0,
+            2 => 0.8,
+            3 => 0.7,
+            _ => 0.6,
         }
     }
 }
 
+// How the numbers of particles are decided.
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub enum ParticleMode {
+    // the expected numbers, which may be fractional
+    Expected,
+    // numbers drawn from the distributions with the seed
+    Sampled(u64),
+}
+
 // The `Preference` enum means a preferable role in a battle for artifacts,
 // weapons and characters. Implementations should allow some of them to prefer
 // one or more roles (should be implemented like `Vec<Preference>`). If such
@@ -386,10 +499,28 @@ impl PartialEq<str> for Preference {
     }
 }
 
-pub fn combination_filter(cr: &CharacterRecord, wr: &WeaponRecord, ar: &Artifact, args: &Args) -> bool {
-    if cr.version > args.character_version ||
-       wr.version > args.weapon_version ||
-       ar.version > args.artifact_version {
+// the latest versions of characters, weapons and artifacts to be simulated
+#[derive(Debug, Copy, Clone)]
+pub struct Versions {
+    pub character: f32,
+    pub weapon: f32,
+    pub artifact: f32,
+}
+
+impl Default for Versions {
+    fn default() -> Self {
+        Self {
+            character: 2.5,
+            weapon: 2.5,
+            artifact: 2.5,
+        }
+    }
+}
+
+pub fn combination_filter(cr: &CharacterRecord, wr: &WeaponRecord, ar: &Artifact, versions: &Versions) -> bool {
+    if cr.version > versions.character ||
+       wr.version > versions.weapon ||
+       ar.version > versions.artifact {
         return false;
     }
 
@@ -473,6 +604,21 @@ impl Vision {
             Physical => &PHYSICAL_GAUGE,
         }
     }
+
+    // names in files, e.g. "pyro"
+    pub fn parse(key: &str) -> Option<Self> {
+        match key {
+            "pyro" => Some(Pyro),
+            "hydro" => Some(Hydro),
+            "electro" => Some(Electro),
+            "cryo" => Some(Cryo),
+            "anemo" => Some(Anemo),
+            "geo" => Some(Geo),
+            "dendro" => Some(Dendro),
+            "physical" => Some(Physical),
+            _ => None,
+        }
+    }
 }
 
 impl From<String> for Vision {
@@ -553,57 +699,6 @@ impl PeriodicStack {
     }
 }
 
-#[derive(Debug)]
-pub struct GearScore {
-    pub score: f32
-}
-
-impl GearScore {
-    const HP: f32 = 5.83 / 3.89;
-    const ATK: f32 = 5.83 / 3.89;
-    const DEF: f32 = 7.29 / 3.89;
-    // const CR: f32 = 1.0; // 3.89 / 3.89
-    const CD: f32 = 7.77 / 3.89;
-    const ER: f32 = 6.48 / 3.89;
-    const EM: f32 = 23.310 / 3.89;
-
-    pub fn new(score: f32) -> Self {
-        Self {
-            score
-        }
-    }
-
-    pub fn hp(&self, r: f32) -> f32 {
-        self.score * r / 100.0 * Self::HP
-    }
-
-    pub fn atk(&self, r: f32) -> f32 {
-        self.score * r / 100.0 * Self::ATK
-    }
-
-    pub fn def(&self, r: f32) -> f32 {
-        self.score * r / 100.0 * Self::DEF
-    }
-
-    pub fn cr(&self, r: f32) -> f32 {
-        self.score * r / 100.0
-    }
-
-    pub fn cd(&self, r: f32) -> f32 {
-        self.score * r / 100.0 * Self::CD
-    }
-
-    pub fn er(&self, r: f32) -> f32 {
-        self.score * r / 100.0 * Self::ER
-    }
-
-    pub fn em(&self, r: f32) -> f32 {
-        self.score * r / 100.0 * Self::EM
-    }
-}
-
-pub const SCORE: GearScore = GearScore { score: 140.0 };
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -611,6 +706,36 @@ mod tests {
     use crate::sim2::weapons;
     use crate::sim2::artifact;
 
+    #[test]
+    fn particle_sample() {
+        let p = Particle::chance(Pyro, &[(2., 0.5), (3., 0.5)]);
+        assert_eq!(p.on_field_energy(&Pyro), 7.5);
+        let mut rng = Rng::new(1);
+        let counts: Vec<f32> = (0..1000).map(|_| p.sample(&mut rng).on_field_energy(&Physical)).collect();
+        assert!(counts.iter().all(|n| *n == 2. || *n == 3.));
+        let mean = counts.iter().sum::<f32>() / 1000.;
+        assert!((mean - 2.5).abs() < 0.1);
+        // integers around the expected number
+        let p = Particle::new(Electro, 4.4);
+        let counts: Vec<f32> = (0..1000).map(|_| p.sample(&mut rng).on_field_energy(&Physical)).collect();
+        assert!(counts.iter().all(|n| *n == 4. || *n == 5.));
+        let mean = counts.iter().sum::<f32>() / 1000.;
+        assert!((mean - 4.4).abs() < 0.1);
+    }
+
+    #[test]
+    fn off_field_energy() {
+        let p = Particle::new(Pyro, 1.);
+        // a particle of the same element, a neutral one and one of another
+        // element
+        let energy = |members: usize| [Pyro, Physical, Cryo].iter()
+            .map(|e| Particle::new(*e, 1.).off_field_energy(&Pyro, members)).collect::<Vec<f32>>();
+        assert_eq!(p.off_field_energy(&Pyro, 1), 3.);
+        assert!(energy(2).iter().zip([2.4, 1.6, 0.8].iter()).all(|(a, b)| approx_equal(*a, *b, 4)));
+        assert!(energy(3).iter().zip([2.1, 1.4, 0.7].iter()).all(|(a, b)| approx_equal(*a, *b, 4)));
+        assert!(energy(4).iter().zip([1.8, 1.2, 0.6].iter()).all(|(a, b)| approx_equal(*a, *b, 4)));
+    }
+
     #[test]
     fn usb_eq() {
         assert_eq!(NOBLESSE_OBLIGE, UnstackableBuff(1));
@@ -641,8 +766,8 @@ mod tests {
         let c = characters::pyro::Diluc::record();
         let w = weapons::claymore_4star::RainslasherR5::record();
         let a = artifact::ViridescentVenerer::record();
-        let args = Args::default();
-        assert!(!combination_filter(&c, &w, &a, &args));
+        let versions = Versions::default();
+        assert!(!combination_filter(&c, &w, &a, &versions));
     }
 
     #[test]
@@ -650,8 +775,8 @@ mod tests {
         let c = characters::pyro::Diluc::record();
         let w = weapons::claymore_4star::RainslasherR5::record();
         let a = artifact::GladiatorsFinale::record();
-        let args = Args::default();
-        assert!(combination_filter(&c, &w, &a, &args));
+        let versions = Versions::default();
+        assert!(combination_filter(&c, &w, &a, &versions));
     }
 
     #[test]
@@ -659,8 +784,8 @@ mod tests {
         let c = characters::electro::Razor::record();
         let w = weapons::claymore_4star::RainslasherR5::record();
         let a = artifact::PaleFlame::record();
-        let args = Args::default();
-        assert!(combination_filter(&c, &w, &a, &args));
+        let versions = Versions::default();
+        assert!(combination_filter(&c, &w, &a, &versions));
         // assert!(combination_filter_supporter(&c, &w, &a, &args));
     }
 
@@ -669,8 +794,8 @@ mod tests {
         let c = characters::electro::Razor::record();
         let w = weapons::claymore_4star::RainslasherR5::record();
         let a = artifact::ThunderingFury::record();
-        let args = Args::default();
-        assert!(combination_filter(&c, &w, &a, &args));
+        let versions = Versions::default();
+        assert!(combination_filter(&c, &w, &a, &versions));
     }
 
     #[test]
@@ -678,8 +803,8 @@ mod tests {
         let c = characters::hydro::Xingqiu::record();
         let w = weapons::sword_4star::PrototypeRancourR5::record();
         let a = artifact::BlizzardStrayer::record();
-        let args = Args::default();
-        assert!(combination_filter(&c, &w, &a, &args));
+        let versions = Versions::default();
+        assert!(combination_filter(&c, &w, &a, &versions));
     }
 
     #[test]
@@ -687,7 +812,7 @@ mod tests {
         let c = characters::cryo::Kaeya::record();
         let w = weapons::sword_4star::PrototypeRancourR5::record();
         let a = artifact::BlizzardStrayer::record();
-        let args = Args::default();
-        assert!(combination_filter(&c, &w, &a, &args));
+        let versions = Versions::default();
+        assert!(combination_filter(&c, &w, &a, &versions));
     }
 }
@@ -0,0 +1,33 @@
+// xorshift64*, which is enough to sample candidates of searches and
+// particles reproducibly
+#[derive(Debug, Clone)]
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self(seed.max(1))
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 >> 12;
+        self.0 ^= self.0 << 25;
+        self.0 ^= self.0 >> 27;
+        self.0.wrapping_mul(0x2545F4914F6CDD1D)
+    }
+
+    // a number in `0..n`
+    pub fn below(&mut self, n: usize) -> usize {
+        (self.next_u64() % n as u64) as usize
+    }
+
+    // a number in `0..1`
+    pub fn chance(&mut self) -> f32 {
+        (self.next_u64() >> 40) as f32 / (1u64 << 24) as f32
+    }
+}
+
+// a roll for procs of weapons. it is always 0, so that procs always trigger.
+pub fn chance() -> f32 {
+    // rand::random::<f32>()
+    0.0
+}
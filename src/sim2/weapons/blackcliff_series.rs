@@ -0,0 +1,174 @@
+use crate::sim2::state::State;
+use crate::sim2::timeline::{ActionState, Timeline};
+use crate::sim2::attack::{Attack, WeaponAttack};
+use crate::sim2::types::WeaponType;
+use crate::sim2::record::{CharacterData, WeaponRecord, Enemy};
+
+use WeaponType::*;
+
+// After defeating an opponent, ATK is increased by 12~24% for 30s. This effect
+// has a maximum of 3 stacks, and the duration of each stack is independent of
+// the others.
+//
+// One stack is always active and included in the records. The other stacks
+// are given by `set_stacks` and last 30s from the beginning.
+pub struct PressTheAdvantage {
+    stack: f32,
+}
+
+impl PressTheAdvantage {
+    pub fn new() -> Self {
+        Self {
+            stack: 1.,
+        }
+    }
+}
+
+impl Timeline for PressTheAdvantage {}
+
+impl WeaponAttack for PressTheAdvantage {
+    fn modify(&mut self, action_state: &ActionState, data: &CharacterData, attack: &mut Attack, state: &mut State, enemy: &mut Enemy) -> () {
+        if attack.idx == data.idx && attack.time < 30. {
+            state.atk += 24. * (self.stack - 1.);
+        }
+    }
+
+    fn set_stacks(&mut self, stacks: f32) -> () {
+        self.stack = stacks.clamp(1., 3.);
+    }
+}
+
+pub struct BlackcliffLongswordR5(PressTheAdvantage);
+
+impl BlackcliffLongswordR5 {
+    pub fn record() -> WeaponRecord {
+        WeaponRecord::default()
+            .name("Blackcliff Longsword").type_(Sword).version(1.0)
+            .base_atk(565.0)
+            .atk(24.0).cd(36.8)
+    }
+
+    pub fn new() -> Self {
+        Self(PressTheAdvantage::new())
+    }
+}
+
+impl Timeline for BlackcliffLongswordR5 {}
+
+impl WeaponAttack for BlackcliffLongswordR5 {
+    fn modify(&mut self, action_state: &ActionState, data: &CharacterData, attack: &mut Attack, state: &mut State, enemy: &mut Enemy) -> () {
+        self.0.modify(action_state, data, attack, state, enemy);
+    }
+
+    fn set_stacks(&mut self, stacks: f32) -> () {
+        self.0.set_stacks(stacks);
+    }
+}
+
+pub struct BlackcliffSlasherR5(PressTheAdvantage);
+
+impl BlackcliffSlasherR5 {
+    pub fn record() -> WeaponRecord {
+        WeaponRecord::default()
+            .name("Blackcliff Slasher").type_(Claymore).version(1.0)
+            .base_atk(510.0)
+            .atk(24.0).cd(55.1)
+    }
+
+    pub fn new() -> Self {
+        Self(PressTheAdvantage::new())
+    }
+}
+
+impl Timeline for BlackcliffSlasherR5 {}
+
+impl WeaponAttack for BlackcliffSlasherR5 {
+    fn modify(&mut self, action_state: &ActionState, data: &CharacterData, attack: &mut Attack, state: &mut State, enemy: &mut Enemy) -> () {
+        self.0.modify(action_state, data, attack, state, enemy);
+    }
+
+    fn set_stacks(&mut self, stacks: f32) -> () {
+        self.0.set_stacks(stacks);
+    }
+}
+
+pub struct BlackcliffPoleR5(PressTheAdvantage);
+
+impl BlackcliffPoleR5 {
+    pub fn record() -> WeaponRecord {
+        WeaponRecord::default()
+            .name("Blackcliff Pole").type_(Polearm).version(1.0)
+            .base_atk(510.0)
+            .atk(24.0).cd(55.1)
+    }
+
+    pub fn new() -> Self {
+        Self(PressTheAdvantage::new())
+    }
+}
+
+impl Timeline for BlackcliffPoleR5 {}
+
+impl WeaponAttack for BlackcliffPoleR5 {
+    fn modify(&mut self, action_state: &ActionState, data: &CharacterData, attack: &mut Attack, state: &mut State, enemy: &mut Enemy) -> () {
+        self.0.modify(action_state, data, attack, state, enemy);
+    }
+
+    fn set_stacks(&mut self, stacks: f32) -> () {
+        self.0.set_stacks(stacks);
+    }
+}
+
+pub struct BlackcliffWarbowR5(PressTheAdvantage);
+
+impl BlackcliffWarbowR5 {
+    pub fn record() -> WeaponRecord {
+        WeaponRecord::default()
+            .name("Blackcliff Warbow").type_(Bow).version(1.0)
+            .base_atk(510.0)
+            .atk(24.0).cd(55.1)
+    }
+
+    pub fn new() -> Self {
+        Self(PressTheAdvantage::new())
+    }
+}
+
+impl Timeline for BlackcliffWarbowR5 {}
+
+impl WeaponAttack for BlackcliffWarbowR5 {
+    fn modify(&mut self, action_state: &ActionState, data: &CharacterData, attack: &mut Attack, state: &mut State, enemy: &mut Enemy) -> () {
+        self.0.modify(action_state, data, attack, state, enemy);
+    }
+
+    fn set_stacks(&mut self, stacks: f32) -> () {
+        self.0.set_stacks(stacks);
+    }
+}
+
+pub struct BlackcliffAgateR5(PressTheAdvantage);
+
+impl BlackcliffAgateR5 {
+    pub fn record() -> WeaponRecord {
+        WeaponRecord::default()
+            .name("Blackcliff Agate").type_(Catalyst).version(1.0)
+            .base_atk(510.0)
+            .atk(24.0).cd(55.1)
+    }
+
+    pub fn new() -> Self {
+        Self(PressTheAdvantage::new())
+    }
+}
+
+impl Timeline for BlackcliffAgateR5 {}
+
+impl WeaponAttack for BlackcliffAgateR5 {
+    fn modify(&mut self, action_state: &ActionState, data: &CharacterData, attack: &mut Attack, state: &mut State, enemy: &mut Enemy) -> () {
+        self.0.modify(action_state, data, attack, state, enemy);
+    }
+
+    fn set_stacks(&mut self, stacks: f32) -> () {
+        self.0.set_stacks(stacks);
+    }
+}
@@ -1,10 +1,10 @@
 use crate::sim2::state::State;
 use crate::sim2::timeline::{ActionState, Timeline};
-use crate::sim2::attack::{Attack, WeaponAttack};
+use crate::sim2::attack::{Attack, Scaling, WeaponAttack};
 use crate::sim2::types::{DamageType, CharacterAction, WeaponType, FieldEnergy, Vision};
 use crate::sim2::element::{ElementalGauge, ElementalReactionType, ElementalReaction, PHYSICAL_GAUGE};
 use crate::sim2::record::{CharacterData, WeaponRecord, Enemy};
-use crate::sim2::testutil;
+use crate::sim2::util;
 
 use WeaponType::*;
 // use Vision::*;
@@ -52,6 +52,8 @@ impl CompoundBowR5 {
 }
 
 impl Timeline for CompoundBowR5 {
+    fn accelerates(&self) -> bool { true }
+
     fn accelerate(&mut self, field_energy: &mut Vec<FieldEnergy>, event: &CharacterAction, state: &mut ActionState, data: &CharacterData) -> () {
         if event.is_na() || event.is_ca() {
             self.time = state.current_time;
@@ -123,7 +125,7 @@ impl Timeline for TheViridescentHuntR5 {}
 impl WeaponAttack for TheViridescentHuntR5 {
     fn attack(&mut self, time: f32, event: &CharacterAction, data: &CharacterData, atk_queue: &mut Vec<Attack>, state: &mut State, enemy: &mut Enemy) -> () {
         if time - self.time > 10. &&
-           testutil::chance() < 0.5 &&
+           util::chance() < 0.5 &&
            (event.is_na() || event.is_ca()) {
             self.time = time;
             self.stack = 0;
@@ -139,6 +141,7 @@ impl WeaponAttack for TheViridescentHuntR5 {
                 aura_application: false,
                 time,
                 idx: data.idx,
+                scaling: Scaling::Atk,
             });
         }
     }
@@ -151,24 +154,6 @@ impl WeaponAttack for TheViridescentHuntR5 {
 }
 
 // one stack is always active
-// After defeating an opponent, ATK is increased by 12~24% for 30s. This effect
-// has a maximum of 3 stacks, and the duration of each stack is independent of
-// the others.
-pub struct BlackcliffWarbowR5;
-
-impl Timeline for BlackcliffWarbowR5 {}
-
-impl WeaponAttack for BlackcliffWarbowR5 {}
-
-impl BlackcliffWarbowR5 {
-    pub fn record() -> WeaponRecord {
-        WeaponRecord::default()
-            .name("Blackcliff Warbow").type_(Bow).version(1.0)
-            .base_atk(510.0)
-            .atk(24.0).cd(55.1)
-    }
-}
-
 pub struct RoyalBowR5;
 
 impl Timeline for RoyalBowR5 {}
@@ -1,10 +1,10 @@
 use crate::sim2::state::State;
 use crate::sim2::timeline::{ActionState, Timeline};
-use crate::sim2::attack::{Attack, WeaponAttack};
+use crate::sim2::attack::{Attack, Scaling, WeaponAttack};
 use crate::sim2::types::{DamageType, CharacterAction, WeaponType, FieldEnergy, Vision};
 use crate::sim2::element::{ElementalGauge, ElementalReactionType, ElementalReaction, PHYSICAL_GAUGE};
 use crate::sim2::record::{CharacterData, WeaponRecord, Enemy};
-use crate::sim2::testutil;
+use crate::sim2::util;
 
 use WeaponType::*;
 // use Vision::*;
@@ -116,22 +116,6 @@ impl WeaponAttack for SolarPearlR5 {
     }
 }
 
-// one stack is always active
-pub struct BlackcliffAgateR5;
-
-impl Timeline for BlackcliffAgateR5 {}
-
-impl WeaponAttack for BlackcliffAgateR5 {}
-
-impl BlackcliffAgateR5 {
-    pub fn record() -> WeaponRecord {
-        WeaponRecord::default()
-            .name("Blackcliff Agate").type_(Catalyst).version(1.0)
-            .base_atk(510.0)
-            .atk(24.0).cd(55.1)
-    }
-}
-
 pub struct RoyalGrimoireR5;
 
 impl Timeline for RoyalGrimoireR5 {}
@@ -208,7 +192,7 @@ impl Timeline for EyeOfPerceptionR5 {}
 impl WeaponAttack for EyeOfPerceptionR5 {
     fn attack(&mut self, time: f32, event: &CharacterAction, data: &CharacterData, atk_queue: &mut Vec<Attack>, state: &mut State, enemy: &mut Enemy) -> () {
         if time - self.time > 8. &&
-           testutil::chance() < 0.5 &&
+           util::chance() < 0.5 &&
            (event.is_na() || event.is_ca()) {
             self.time = time;
             atk_queue.push(Attack {
@@ -218,6 +202,7 @@ impl WeaponAttack for EyeOfPerceptionR5 {
                 aura_application: false,
                 time,
                 idx: data.idx,
+                scaling: Scaling::Atk,
             });
         }
     }
@@ -254,7 +239,7 @@ impl WeaponAttack for TheWidsithR5 {
     fn modify(&mut self, action_state: &ActionState, data: &CharacterData, attack: &mut Attack, state: &mut State, enemy: &mut Enemy) -> () {
         if action_state.current_time - self.time >= 30. {
             self.time = action_state.current_time;
-            let p = testutil::chance();
+            let p = util::chance();
             self.random_theme_song = if p > 0.6666 {
                 0
             } else if p > 0.3333 {
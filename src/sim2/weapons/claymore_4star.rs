@@ -1,10 +1,10 @@
 use crate::sim2::state::State;
 use crate::sim2::timeline::{ActionState, Timeline};
-use crate::sim2::attack::{Attack, WeaponAttack};
+use crate::sim2::attack::{Attack, Scaling, WeaponAttack};
 use crate::sim2::types::{DamageType, CharacterAction, WeaponType, FieldEnergy, Vision};
 use crate::sim2::element::{ElementalGauge, ElementalReactionType, ElementalReaction, PHYSICAL_GAUGE};
 use crate::sim2::record::{CharacterData, WeaponRecord, Enemy};
-use crate::sim2::testutil;
+use crate::sim2::util;
 
 use WeaponType::*;
 // use Vision::*;
@@ -35,7 +35,7 @@ impl Timeline for PrototypeArchaicR5 {}
 impl WeaponAttack for PrototypeArchaicR5 {
     fn attack(&mut self, time: f32, event: &CharacterAction, data: &CharacterData, atk_queue: &mut Vec<Attack>, state: &mut State, enemy: &mut Enemy) -> () {
         if time - self.time > 15. &&
-           testutil::chance() < 0.5 &&
+           util::chance() < 0.5 &&
            (event.is_na() || event.is_ca()) {
             self.time = time;
             atk_queue.push(Attack {
@@ -45,6 +45,7 @@ impl WeaponAttack for PrototypeArchaicR5 {
                 aura_application: false,
                 time,
                 idx: data.idx,
+                scaling: Scaling::Atk,
             });
         }
     }
@@ -141,22 +142,6 @@ impl WeaponAttack for SerpentSpineR5 {
     }
 }
 
-// one stack is always active
-pub struct BlackcliffSlasherR5;
-
-impl Timeline for BlackcliffSlasherR5 {}
-
-impl WeaponAttack for BlackcliffSlasherR5 {}
-
-impl BlackcliffSlasherR5 {
-    pub fn record() -> WeaponRecord {
-        WeaponRecord::default()
-            .name("Blackcliff Slasher").type_(Claymore).version(1.0)
-            .base_atk(510.0)
-            .atk(24.0).cd(55.1)
-    }
-}
-
 pub struct RoyalGreatswordR5;
 
 impl Timeline for RoyalGreatswordR5 {}
@@ -24,6 +24,8 @@ impl Windfall {
 }
 
 impl Timeline for Windfall {
+    fn accelerates(&self) -> bool { true }
+
     fn accelerate(&mut self, field_energy: &mut Vec<FieldEnergy>, event: &CharacterAction, state: &mut ActionState, data: &CharacterData) -> () {
         if *event != CharacterAction::StandStill && state.current_time - self.time >= 6. {
             self.time = state.current_time;
@@ -54,6 +56,8 @@ impl FavoniusGreatswordR5 {
 }
 
 impl Timeline for FavoniusGreatswordR5 {
+    fn accelerates(&self) -> bool { true }
+
     fn accelerate(&mut self, field_energy: &mut Vec<FieldEnergy>, event: &CharacterAction, state: &mut ActionState, data: &CharacterData) -> () {
         self.0.accelerate(field_energy, event, state, data);
     }
@@ -79,6 +83,8 @@ impl FavoniusSwordR5 {
 }
 
 impl Timeline for FavoniusSwordR5 {
+    fn accelerates(&self) -> bool { true }
+
     fn accelerate(&mut self, field_energy: &mut Vec<FieldEnergy>, event: &CharacterAction, state: &mut ActionState, data: &CharacterData) -> () {
         self.0.accelerate(field_energy, event, state, data);
     }
@@ -104,6 +110,8 @@ impl FavoniusLanceR5 {
 }
 
 impl Timeline for FavoniusLanceR5 {
+    fn accelerates(&self) -> bool { true }
+
     fn accelerate(&mut self, field_energy: &mut Vec<FieldEnergy>, event: &CharacterAction, state: &mut ActionState, data: &CharacterData) -> () {
         self.0.accelerate(field_energy, event, state, data);
     }
@@ -129,6 +137,8 @@ impl FavoniusWarbowR5 {
 }
 
 impl Timeline for FavoniusWarbowR5 {
+    fn accelerates(&self) -> bool { true }
+
     fn accelerate(&mut self, field_energy: &mut Vec<FieldEnergy>, event: &CharacterAction, state: &mut ActionState, data: &CharacterData) -> () {
         self.0.accelerate(field_energy, event, state, data);
     }
@@ -154,6 +164,8 @@ impl FavoniusCodexR5 {
 }
 
 impl Timeline for FavoniusCodexR5 {
+    fn accelerates(&self) -> bool { true }
+
     fn accelerate(&mut self, field_energy: &mut Vec<FieldEnergy>, event: &CharacterAction, state: &mut ActionState, data: &CharacterData) -> () {
         self.0.accelerate(field_energy, event, state, data);
     }
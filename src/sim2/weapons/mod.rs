@@ -5,6 +5,7 @@ pub mod bow_4star;
 pub mod catalyst_4star;
 pub mod favonius_series;
 pub mod sacrificial_series;
+pub mod blackcliff_series;
 pub mod version_1_5star;
 pub mod version_1_1;
 pub mod version_1_2;
@@ -30,6 +31,7 @@ use bow_4star::*;
 use catalyst_4star::*;
 use favonius_series::*;
 use sacrificial_series::*;
+use blackcliff_series::*;
 use version_1_5star::*;
 use version_1_1::*;
 use version_1_2::*;
@@ -441,7 +443,7 @@ pub fn all() -> Vec<(WeaponRecord, WeaponUnion)> {
     // sword_4star
     (PrototypeRancourR5::record(), WeaponUnion::PrototypeRancourR5(PrototypeRancourR5::new())),
     (TheBlackSwordR5::record(), WeaponUnion::TheBlackSwordR5(TheBlackSwordR5)),
-    (BlackcliffLongswordR5::record(), WeaponUnion::BlackcliffLongswordR5(BlackcliffLongswordR5)),
+    (BlackcliffLongswordR5::record(), WeaponUnion::BlackcliffLongswordR5(BlackcliffLongswordR5::new())),
     (RoyalLongswordR5::record(), WeaponUnion::RoyalLongswordR5(RoyalLongswordR5)),
     (HarbingerOfDawnR5::record(), WeaponUnion::HarbingerOfDawnR5(HarbingerOfDawnR5)),
     (TheFluteR5::record(), WeaponUnion::TheFluteR5(TheFluteR5::new())),
@@ -450,14 +452,14 @@ pub fn all() -> Vec<(WeaponRecord, WeaponUnion)> {
     (PrototypeArchaicR5::record(), WeaponUnion::PrototypeArchaicR5(PrototypeArchaicR5::new())),
     (WhiteblindR5::record(), WeaponUnion::WhiteblindR5(WhiteblindR5::new())),
     (SerpentSpineR5::record(), WeaponUnion::SerpentSpineR5(SerpentSpineR5::new())),
-    (BlackcliffSlasherR5::record(), WeaponUnion::BlackcliffSlasherR5(BlackcliffSlasherR5)),
+    (BlackcliffSlasherR5::record(), WeaponUnion::BlackcliffSlasherR5(BlackcliffSlasherR5::new())),
     (RoyalGreatswordR5::record(), WeaponUnion::RoyalGreatswordR5(RoyalGreatswordR5)),
     (RainslasherR5::record(), WeaponUnion::RainslasherR5(RainslasherR5::new())),
     // polearm_4star
     (PrototypeStarglitterR5::record(), WeaponUnion::PrototypeStarglitterR5(PrototypeStarglitterR5::new())),
     (CrescentPikeR5::record(), WeaponUnion::CrescentPikeR5(CrescentPikeR5::new())),
     (DeathmatchR5::record(), WeaponUnion::DeathmatchR5(DeathmatchR5)),
-    (BlackcliffPoleR5::record(), WeaponUnion::BlackcliffPoleR5(BlackcliffPoleR5)),
+    (BlackcliffPoleR5::record(), WeaponUnion::BlackcliffPoleR5(BlackcliffPoleR5::new())),
     (RoyalSpearR5::record(), WeaponUnion::RoyalSpearR5(RoyalSpearR5)),
     (WhiteTasselR5::record(), WeaponUnion::WhiteTasselR5(WhiteTasselR5)),
     (DragonsBaneR5::record(), WeaponUnion::DragonsBaneR5(DragonsBaneR5::new())),
@@ -465,7 +467,7 @@ pub fn all() -> Vec<(WeaponRecord, WeaponUnion)> {
     (PrototypeCrescentR5::record(), WeaponUnion::PrototypeCrescentR5(PrototypeCrescentR5)),
     (CompoundBowR5::record(), WeaponUnion::CompoundBowR5(CompoundBowR5::new())),
     (TheViridescentHuntR5::record(), WeaponUnion::TheViridescentHuntR5(TheViridescentHuntR5::new())),
-    (BlackcliffWarbowR5::record(), WeaponUnion::BlackcliffWarbowR5(BlackcliffWarbowR5)),
+    (BlackcliffWarbowR5::record(), WeaponUnion::BlackcliffWarbowR5(BlackcliffWarbowR5::new())),
     (RoyalBowR5::record(), WeaponUnion::RoyalBowR5(RoyalBowR5)),
     (SlingshotR5::record(), WeaponUnion::SlingshotR5(SlingshotR5)),
     (RustR5::record(), WeaponUnion::RustR5(RustR5)),
@@ -474,7 +476,7 @@ pub fn all() -> Vec<(WeaponRecord, WeaponUnion)> {
     (PrototypeAmberR5::record(), WeaponUnion::PrototypeAmberR5(PrototypeAmberR5)),
     (MappaMareR5::record(), WeaponUnion::MappaMareR5(MappaMareR5::new())),
     (SolarPearlR5::record(), WeaponUnion::SolarPearlR5(SolarPearlR5::new())),
-    (BlackcliffAgateR5::record(), WeaponUnion::BlackcliffAgateR5(BlackcliffAgateR5)),
+    (BlackcliffAgateR5::record(), WeaponUnion::BlackcliffAgateR5(BlackcliffAgateR5::new())),
     (RoyalGrimoireR5::record(), WeaponUnion::RoyalGrimoireR5(RoyalGrimoireR5)),
     (ThrillingTalesOfDragonSlayersR5::record(), WeaponUnion::ThrillingTalesOfDragonSlayersR5(ThrillingTalesOfDragonSlayersR5::new())),
     (EyeOfPerceptionR5::record(), WeaponUnion::EyeOfPerceptionR5(EyeOfPerceptionR5::new())),
@@ -517,8 +519,8 @@ pub fn all() -> Vec<(WeaponRecord, WeaponUnion)> {
     (PrimordialJadeGS::record(), WeaponUnion::PrimordialJadeGS(PrimordialJadeGS::new())),
     (PrimordialJadeVista::record(), WeaponUnion::PrimordialJadeVista(PrimordialJadeVista::new())),
     (StaffOfHoma::record(), WeaponUnion::StaffOfHoma(StaffOfHoma::new())),
-    (LithicSpear::record(), WeaponUnion::LithicSpear(LithicSpear)),
-    (LithicBlade::record(), WeaponUnion::LithicBlade(LithicBlade)),
+    (LithicSpear::record(), WeaponUnion::LithicSpear(LithicSpear::new())),
+    (LithicBlade::record(), WeaponUnion::LithicBlade(LithicBlade::new())),
     // version_1_4
     (ElegyForTheEnd::record(), WeaponUnion::ElegyForTheEnd(ElegyForTheEnd::new())),
     (TheAlleyFlash::record(), WeaponUnion::TheAlleyFlash(TheAlleyFlash)),
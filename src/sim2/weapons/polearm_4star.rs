@@ -1,6 +1,6 @@
 use crate::sim2::state::State;
 use crate::sim2::timeline::{ActionState, Timeline};
-use crate::sim2::attack::{Attack, WeaponAttack};
+use crate::sim2::attack::{Attack, Scaling, WeaponAttack};
 use crate::sim2::types::{DamageType, CharacterAction, WeaponType, FieldEnergy, Vision};
 use crate::sim2::element::{ElementalGauge, ElementalReactionType, ElementalReaction, PHYSICAL_GAUGE};
 use crate::sim2::record::{CharacterData, WeaponRecord, Enemy};
@@ -86,6 +86,7 @@ impl WeaponAttack for CrescentPikeR5 {
                 aura_application: false,
                 time,
                 idx: data.idx,
+                scaling: Scaling::Atk,
             });
         }
     }
@@ -106,22 +107,6 @@ impl DeathmatchR5 {
     }
 }
 
-// one stack is always active
-pub struct BlackcliffPoleR5;
-
-impl Timeline for BlackcliffPoleR5 {}
-
-impl WeaponAttack for BlackcliffPoleR5 {}
-
-impl BlackcliffPoleR5 {
-    pub fn record() -> WeaponRecord {
-        WeaponRecord::default()
-            .name("Blackcliff Pole").type_(Polearm).version(1.0)
-            .base_atk(510.0)
-            .atk(24.0).cd(55.1)
-    }
-}
-
 pub struct RoyalSpearR5;
 
 impl Timeline for RoyalSpearR5 {}
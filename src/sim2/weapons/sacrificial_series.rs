@@ -23,6 +23,8 @@ impl Composed {
 impl WeaponAttack for Composed {}
 
 impl Timeline for Composed {
+    fn accelerates(&self) -> bool { true }
+
     fn accelerate(&mut self, field_energy: &mut Vec<FieldEnergy>, event: &CharacterAction, state: &mut ActionState, data: &CharacterData) -> () {
         if data.character.name == "Sangonomiya Kokomi" {
             return;
@@ -56,6 +58,8 @@ impl SacrificialSwordR5 {
 impl WeaponAttack for SacrificialSwordR5 {}
 
 impl Timeline for SacrificialSwordR5 {
+    fn accelerates(&self) -> bool { true }
+
     fn accelerate(&mut self, field_energy: &mut Vec<FieldEnergy>, event: &CharacterAction, state: &mut ActionState, data: &CharacterData) -> () {
         self.0.accelerate(field_energy, event, state, data);
     }
@@ -81,6 +85,8 @@ impl SacrificialGreatswordR5 {
 impl WeaponAttack for SacrificialGreatswordR5 {}
 
 impl Timeline for SacrificialGreatswordR5 {
+    fn accelerates(&self) -> bool { true }
+
     fn accelerate(&mut self, field_energy: &mut Vec<FieldEnergy>, event: &CharacterAction, state: &mut ActionState, data: &CharacterData) -> () {
         self.0.accelerate(field_energy, event, state, data);
     }
@@ -108,6 +114,8 @@ impl SacrificialBowR5 {
 impl WeaponAttack for SacrificialBowR5 {}
 
 impl Timeline for SacrificialBowR5 {
+    fn accelerates(&self) -> bool { true }
+
     fn accelerate(&mut self, field_energy: &mut Vec<FieldEnergy>, event: &CharacterAction, state: &mut ActionState, data: &CharacterData) -> () {
         self.0.accelerate(field_energy, event, state, data);
     }
@@ -133,6 +141,8 @@ impl SacrificialFragmentsR5 {
 impl WeaponAttack for SacrificialFragmentsR5 {}
 
 impl Timeline for SacrificialFragmentsR5 {
+    fn accelerates(&self) -> bool { true }
+
     fn accelerate(&mut self, field_energy: &mut Vec<FieldEnergy>, event: &CharacterAction, state: &mut ActionState, data: &CharacterData) -> () {
         self.0.accelerate(field_energy, event, state, data);
     }
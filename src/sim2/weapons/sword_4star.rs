@@ -1,6 +1,6 @@
 use crate::sim2::state::State;
 use crate::sim2::timeline::{ActionState, Timeline};
-use crate::sim2::attack::{Attack, WeaponAttack};
+use crate::sim2::attack::{Attack, Scaling, WeaponAttack};
 use crate::sim2::types::{DamageType, CharacterAction, WeaponType, FieldEnergy, Vision};
 use crate::sim2::element::{ElementalGauge, ElementalReactionType, ElementalReaction, PHYSICAL_GAUGE};
 use crate::sim2::record::{CharacterData, WeaponRecord, Enemy};
@@ -71,21 +71,6 @@ impl TheBlackSwordR5 {
     }
 }
 
-// one stack is always active
-pub struct BlackcliffLongswordR5;
-
-impl Timeline for BlackcliffLongswordR5 {}
-
-impl WeaponAttack for BlackcliffLongswordR5 {}
-impl BlackcliffLongswordR5 {
-    pub fn record() -> WeaponRecord {
-        WeaponRecord::default()
-            .name("Blackcliff Longsword").type_(Sword).version(1.0)
-            .base_atk(565.0)
-            .atk(24.0).cd(36.8)
-    }
-}
-
 pub struct RoyalLongswordR5;
 
 impl Timeline for RoyalLongswordR5 {}
@@ -153,6 +138,7 @@ impl WeaponAttack for TheFluteR5 {
                     aura_application: false,
                     time,
                     idx: data.idx,
+                    scaling: Scaling::Atk,
                 });
             }
         }
@@ -150,7 +150,40 @@ impl WeaponAttack for StaffOfHoma {
     }
 }
 
-pub struct LithicSpear;
+// For every character in the party who hails from Liyue, the character who
+// equips this weapon gains 7~11% ATK increase and 3~7% CRIT Rate increase.
+// This effect stacks up to 4 times.
+//
+// One stack is included in the records. The others are given by
+// `set_stacks`.
+pub struct LiyueUnity {
+    stack: f32,
+}
+
+impl LiyueUnity {
+    pub fn new() -> Self {
+        Self {
+            stack: 1.,
+        }
+    }
+}
+
+impl Timeline for LiyueUnity {}
+
+impl WeaponAttack for LiyueUnity {
+    fn modify(&mut self, action_state: &ActionState, data: &CharacterData, attack: &mut Attack, state: &mut State, enemy: &mut Enemy) -> () {
+        if attack.idx == data.idx {
+            state.atk += 11. * (self.stack - 1.);
+            state.cr += 7. * (self.stack - 1.);
+        }
+    }
+
+    fn set_stacks(&mut self, stacks: f32) -> () {
+        self.stack = stacks.clamp(1., 4.);
+    }
+}
+
+pub struct LithicSpear(LiyueUnity);
 
 impl LithicSpear {
     pub fn record() -> WeaponRecord {
@@ -159,13 +192,25 @@ impl LithicSpear {
             .base_atk(565.0)
             .atk(27.6 + 11.0).cr(0.0 + 7.0)
     }
+
+    pub fn new() -> Self {
+        Self(LiyueUnity::new())
+    }
 }
 
 impl Timeline for LithicSpear {}
 
-impl WeaponAttack for LithicSpear {}
+impl WeaponAttack for LithicSpear {
+    fn modify(&mut self, action_state: &ActionState, data: &CharacterData, attack: &mut Attack, state: &mut State, enemy: &mut Enemy) -> () {
+        self.0.modify(action_state, data, attack, state, enemy);
+    }
 
-pub struct LithicBlade;
+    fn set_stacks(&mut self, stacks: f32) -> () {
+        self.0.set_stacks(stacks);
+    }
+}
+
+pub struct LithicBlade(LiyueUnity);
 
 impl LithicBlade {
     pub fn record() -> WeaponRecord {
@@ -174,8 +219,20 @@ impl LithicBlade {
             .base_atk(510.0)
             .atk(41.3 + 11.0).cr(0.0 + 7.0)
     }
+
+    pub fn new() -> Self {
+        Self(LiyueUnity::new())
+    }
 }
 
 impl Timeline for LithicBlade {}
 
-impl WeaponAttack for LithicBlade {}
+impl WeaponAttack for LithicBlade {
+    fn modify(&mut self, action_state: &ActionState, data: &CharacterData, attack: &mut Attack, state: &mut State, enemy: &mut Enemy) -> () {
+        self.0.modify(action_state, data, attack, state, enemy);
+    }
+
+    fn set_stacks(&mut self, stacks: f32) -> () {
+        self.0.set_stacks(stacks);
+    }
+}
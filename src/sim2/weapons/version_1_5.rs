@@ -34,6 +34,8 @@ impl SongOfBrokenPines {
 }
 
 impl Timeline for SongOfBrokenPines {
+    fn accelerates(&self) -> bool { true }
+
     fn accelerate(&mut self, field_energy: &mut Vec<FieldEnergy>, event: &CharacterAction, state: &mut ActionState, data: &CharacterData) -> () {
         if state.current_time - self.time > 20. &&
            (event.is_na() || event.is_ca()) {
@@ -1,10 +1,10 @@
 use crate::sim2::state::State;
 use crate::sim2::timeline::{ActionState, Timeline};
-use crate::sim2::attack::{Attack, WeaponAttack};
+use crate::sim2::attack::{Attack, Scaling, WeaponAttack};
 use crate::sim2::types::{DamageType, CharacterAction, WeaponType, FieldEnergy, Vision};
 use crate::sim2::element::{ElementalGauge, ElementalReactionType, ElementalReaction, PHYSICAL_GAUGE};
 use crate::sim2::record::{CharacterData, WeaponRecord, Enemy};
-use crate::sim2::testutil;
+use crate::sim2::util;
 
 use WeaponType::*;
 // use Vision::*;
@@ -48,6 +48,8 @@ impl SkywardBlade {
 }
 
 impl Timeline for SkywardBlade {
+    fn accelerates(&self) -> bool { true }
+
     fn accelerate(&mut self, field_energy: &mut Vec<FieldEnergy>, event: &CharacterAction, state: &mut ActionState, data: &CharacterData) -> () {
         if event.is_burst() {
             self.time = state.current_time;
@@ -76,6 +78,7 @@ impl WeaponAttack for SkywardBlade {
                 aura_application: false,
                 time,
                 idx: data.idx,
+                scaling: Scaling::Atk,
             });
         }
     }
@@ -136,6 +139,7 @@ impl WeaponAttack for AquilaFavonia {
                 aura_application: false,
                 time,
                 idx: data.idx,
+                scaling: Scaling::Atk,
             });
         }
     }
@@ -199,6 +203,7 @@ impl WeaponAttack for SkywardPride {
                 aura_application: false,
                 time,
                 idx: data.idx,
+                scaling: Scaling::Atk,
             });
         }
     }
@@ -272,7 +277,7 @@ impl Timeline for SkywardSpine {}
 impl WeaponAttack for SkywardSpine {
     fn attack(&mut self, time: f32, event: &CharacterAction, data: &CharacterData, atk_queue: &mut Vec<Attack>, state: &mut State, enemy: &mut Enemy) -> () {
         if time - self.time > 2. &&
-           testutil::chance() < 0.5 &&
+           util::chance() < 0.5 &&
            (event.is_na() || event.is_ca()) {
             self.time = time;
             atk_queue.push(Attack {
@@ -282,6 +287,7 @@ impl WeaponAttack for SkywardSpine {
                 aura_application: false,
                 time,
                 idx: data.idx,
+                scaling: Scaling::Atk,
             });
         }
     }
@@ -414,7 +420,7 @@ impl Timeline for SkywardHarp {}
 impl WeaponAttack for SkywardHarp {
     fn attack(&mut self, time: f32, event: &CharacterAction, data: &CharacterData, atk_queue: &mut Vec<Attack>, state: &mut State, enemy: &mut Enemy) -> () {
         if time - self.time > self.frequency &&
-           testutil::chance() < self.chance &&
+           util::chance() < self.chance &&
            (event.is_na() || event.is_ca() || event.is_skill() || event.is_burst()) {
             self.time = time;
             atk_queue.push(Attack {
@@ -424,6 +430,7 @@ impl WeaponAttack for SkywardHarp {
                 aura_application: false,
                 time,
                 idx: data.idx,
+                scaling: Scaling::Atk,
             });
         }
     }
@@ -517,6 +524,7 @@ impl WeaponAttack for SkywardAtlas {
                 aura_application: false,
                 time,
                 idx: data.idx,
+                scaling: Scaling::Atk,
             });
         }
     }
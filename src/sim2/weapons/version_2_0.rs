@@ -212,6 +212,8 @@ impl AmenomaKageuchi {
 impl WeaponAttack for AmenomaKageuchi {}
 
 impl Timeline for AmenomaKageuchi {
+    fn accelerates(&self) -> bool { true }
+
     fn accelerate(&mut self, field_energy: &mut Vec<FieldEnergy>, event: &CharacterAction, state: &mut ActionState, data: &CharacterData) -> () {
         if state.current_time - self.time >= 5. && event.is_skill() {
             self.time = state.current_time;
@@ -257,6 +259,8 @@ impl KatsuragikiriNagamasa {
 }
 
 impl Timeline for KatsuragikiriNagamasa {
+    fn accelerates(&self) -> bool { true }
+
     fn accelerate(&mut self, field_energy: &mut Vec<FieldEnergy>, event: &CharacterAction, state: &mut ActionState, data: &CharacterData) -> () {
         if state.current_time - self.time >= 10. && event.is_skill() {
             state.energy -= 3.;
@@ -301,6 +305,8 @@ impl KitainCrossSpear {
 }
 
 impl Timeline for KitainCrossSpear {
+    fn accelerates(&self) -> bool { true }
+
     fn accelerate(&mut self, field_energy: &mut Vec<FieldEnergy>, event: &CharacterAction, state: &mut ActionState, data: &CharacterData) -> () {
         if state.current_time - self.time >= 10. && event.is_skill() {
             state.energy -= 3.;